@@ -0,0 +1,250 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#![warn(missing_docs)]
+
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, HeaderMap, HeaderValue};
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+use crate::cache_policy::{
+    CachePolicy, CacheRule, Lookup, OFFLINE_MISS, cached_content_type, validators,
+};
+
+/// Handles caching responses locally. Responses are stored the same way as
+/// the sync client's `CacheMiddleware` stores them, so both clients can share
+/// a cache directory.
+pub struct AsyncCacheMiddleware {
+    directory: String,
+    policy: CachePolicy,
+    offline: bool,
+}
+
+impl AsyncCacheMiddleware {
+    /// Creates a new middleware, with the directory you would like cached
+    /// files to go in specified. Cache file structure beyond that is dictated
+    /// by `cacache`.
+    pub fn new(directory: &str) -> Self {
+        Self { directory: directory.to_owned(), policy: CachePolicy::default(), offline: false }
+    }
+
+    /// Configures which responses get cached, and for how long. Uses
+    /// [CachePolicy::default] if not specified.
+    pub fn policy(mut self, policy: CachePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Configures the middleware to never touch the network. Any cached
+    /// response is served regardless of the policy, and anything that isn't
+    /// cached gets a `504 Gateway Timeout` response.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    async fn cached_response(&self, cache_key: &str, data_type: &str) -> Option<Response> {
+        let data = cacache::read(&self.directory, cache_key).await.ok()?;
+        http::Response::builder()
+            .header(CONTENT_TYPE, data_type)
+            .header(CONTENT_LENGTH, data.len())
+            .status(200)
+            .body(data)
+            .ok()
+            .map(Response::from)
+    }
+
+    async fn store(&self, cache_key: &str, headers: &HeaderMap, body: &[u8]) {
+        let metadata = validators(|name| headers.get(name)?.to_str().ok());
+        if let Ok(integrity) = cacache::write_hash(&self.directory, body).await {
+            let opts =
+                cacache::WriteOpts::new().integrity(integrity).metadata(metadata).size(body.len());
+            let _ = cacache::index::insert_async(self.directory.as_ref(), cache_key, opts).await;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for AsyncCacheMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let data_type = cached_content_type(req.url().path());
+        let cache_key = req.url().to_string();
+        if self.offline {
+            return match self.cached_response(&cache_key, data_type).await {
+                Some(response) => Ok(response),
+                None => Ok(Response::from(
+                    http::Response::builder()
+                        .status(StatusCode::GATEWAY_TIMEOUT)
+                        .header(OFFLINE_MISS, "1")
+                        .body(Vec::new())
+                        .map_err(reqwest_middleware::Error::middleware)?,
+                )),
+            };
+        }
+
+        let rule = self.policy.rule_for(req.url().path());
+        if rule == CacheRule::NoStore {
+            return next.run(req, extensions).await;
+        }
+
+        let entry = cacache::metadata(&self.directory, &cache_key).await.ok().flatten();
+        match rule.lookup(entry.as_ref()) {
+            Lookup::Hit => {
+                if let Some(response) = self.cached_response(&cache_key, data_type).await {
+                    return Ok(response);
+                }
+            }
+            Lookup::Revalidate(headers) => {
+                for (header, value) in headers {
+                    if let Ok(value) = HeaderValue::from_str(value) {
+                        req.headers_mut().insert(header, value);
+                    }
+                }
+            }
+            Lookup::Miss => {}
+        }
+
+        let response = next.run(req, extensions).await?;
+        if response.status() == StatusCode::NOT_MODIFIED && entry.is_some() {
+            if let Some(response) = self.cached_response(&cache_key, data_type).await {
+                return Ok(response);
+            }
+        }
+        if response.status() != StatusCode::OK {
+            return Ok(response);
+        }
+
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        self.store(&cache_key, &headers, &body).await;
+
+        let mut rebuilt = http::Response::builder().status(StatusCode::OK);
+        if let Some(response_headers) = rebuilt.headers_mut() {
+            *response_headers = headers;
+        }
+        Ok(Response::from(rebuilt.body(body).map_err(reqwest_middleware::Error::middleware)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use reqwest::Client;
+    use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+    use std::{env::temp_dir, fs::remove_dir_all, path::Path};
+
+    fn build_client(cache_dir: &Path) -> ClientWithMiddleware {
+        ClientBuilder::new(Client::new())
+            .with(AsyncCacheMiddleware::new(&cache_dir.to_string_lossy()))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn second_request_reads_cache() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/file.txt")
+            .with_status(200)
+            .with_body("some example text")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let full_url = format!("{}/file.txt", server.url());
+
+        let cache_dir = temp_dir().join("async-test01");
+        let _ = remove_dir_all(&cache_dir);
+
+        let client = build_client(&cache_dir);
+        for _ in 0..2 {
+            let response = client.get(&full_url).send().await.unwrap();
+            assert_eq!(response.status(), 200);
+            assert_eq!(response.text().await.unwrap(), "some example text");
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn revalidated_entries_are_served_on_not_modified() {
+        let mut server = Server::new_async().await;
+        let full_url = format!("{}/file.txt", server.url());
+
+        let cache_dir = temp_dir().join("async-test02");
+        let _ = remove_dir_all(&cache_dir);
+
+        let middleware = AsyncCacheMiddleware::new(&cache_dir.to_string_lossy())
+            .policy(CachePolicy::new(CacheRule::Revalidate));
+        let client = ClientBuilder::new(Client::new()).with(middleware).build();
+
+        let first = server
+            .mock("GET", "/file.txt")
+            .match_header("If-None-Match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("ETag", "\"v1\"")
+            .with_body("some example text")
+            .create_async()
+            .await;
+        let revalidation = server
+            .mock("GET", "/file.txt")
+            .match_header("If-None-Match", "\"v1\"")
+            .with_status(304)
+            .create_async()
+            .await;
+
+        let _ = client.get(&full_url).send().await.unwrap();
+        let response = client.get(&full_url).send().await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await.unwrap(), "some example text");
+
+        first.assert_async().await;
+        revalidation.assert_async().await;
+    }
+
+    #[cfg(feature = "sync")]
+    #[tokio::test]
+    async fn offline_serves_entries_written_by_sync_middleware() {
+        use crate::cache_middleware::CacheMiddleware;
+        use ureq::Agent;
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/cdn/languages.json")
+            .with_status(200)
+            .with_body(r#"["en_US"]"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let full_url = format!("{}/cdn/languages.json", server.url());
+
+        let cache_dir = temp_dir().join("async-test03");
+        let _ = remove_dir_all(&cache_dir);
+
+        let sync_dir = cache_dir.to_string_lossy().into_owned();
+        let sync_url = full_url.clone();
+        tokio::task::spawn_blocking(move || {
+            let agent: Agent =
+                Agent::config_builder().middleware(CacheMiddleware::new(&sync_dir)).build().into();
+            agent.get(&sync_url).call().unwrap();
+        })
+        .await
+        .unwrap();
+
+        let middleware = AsyncCacheMiddleware::new(&cache_dir.to_string_lossy()).offline(true);
+        let client = ClientBuilder::new(Client::new()).with(middleware).build();
+
+        let response = client.get(&full_url).send().await.unwrap();
+        assert_eq!(response.text().await.unwrap(), r#"["en_US"]"#);
+
+        let uncached = client.get(format!("{}/file.txt", server.url())).send().await.unwrap();
+        assert_eq!(uncached.status(), 504);
+
+        mock.assert_async().await;
+    }
+}
@@ -4,10 +4,11 @@
 #[cfg(feature = "image")]
 use image::{DynamicImage, load_from_memory};
 
-use http_cache_reqwest::{CACacheManager, Cache, CacheMode, HttpCache, HttpCacheOptions};
+use futures_util::{StreamExt, stream};
 use reqwest::Client;
 use reqwest_middleware::{ClientBuilder as MiddlewareClientBuilder, ClientWithMiddleware};
 use serde::de::DeserializeOwned;
+use std::sync::{Arc, Mutex};
 use url::Url;
 
 #[cfg(test)]
@@ -18,12 +19,16 @@ use crate::models::shared::HasImage;
 
 use crate::{
     ClientError,
-    models::{
-        Challenges, Champion, Champions, ChampionsFull, Items, Maps, MissionAssets, ProfileIcons,
-        Runes, SpellBuffs, SummonerSpells, Translations,
-        champion::ChampionWrapper,
-        tft::{self, Arenas, Augments, HeroAugments, Queues, Regalia, Tacticians, Traits},
-    },
+    async_cache_middleware::AsyncCacheMiddleware,
+    batch::BatchReport,
+    cache_policy::CachePolicy,
+    drift::DriftReport,
+    endpoint::{self, Endpoint, data_endpoints},
+    memo::Memo,
+    models::{self, Champion, Locale, Versions},
+    prefetch::{Listing, Listings, PrefetchPlan, PrefetchProgress, PrefetchQueue, PrefetchReport},
+    retry::RetryPolicy,
+    source::{AsyncDataSource, AsyncHttpSource, BlockingSource, DataSource, Resource},
 };
 
 #[derive(Clone)]
@@ -37,7 +42,15 @@ pub struct AsyncClientBuilder {
     server: String,
     agent: Option<ClientAgent>,
     cache: Option<String>,
+    cache_policy: CachePolicy,
     version: Option<String>,
+    locale: Locale,
+    offline: bool,
+    retry_policy: RetryPolicy,
+    report_drift: bool,
+    concurrency: usize,
+    memoize: Option<usize>,
+    source: Option<Arc<dyn AsyncDataSource>>,
     #[cfg(feature = "cdragon")]
     cdragon_server: String,
 }
@@ -69,7 +82,8 @@ pub struct AsyncClientBuilder {
 /// ```
 ///
 /// Using a provided agent that already has some middleware configured. In
-/// this case, all caching is expected to be handled by the provided agent.
+/// this case, all caching is expected to be handled by the provided agent,
+/// e.g. with [AsyncCacheMiddleware].
 ///
 /// ```no_run
 /// # tokio_test::block_on(async {
@@ -101,7 +115,15 @@ impl AsyncClientBuilder {
             server: "https://ddragon.leagueoflegends.com".to_owned(),
             agent: None,
             cache: None,
+            cache_policy: CachePolicy::default(),
             version: None,
+            locale: Locale::default(),
+            offline: false,
+            retry_policy: RetryPolicy::never(),
+            report_drift: false,
+            concurrency: 8,
+            memoize: None,
+            source: None,
             #[cfg(feature = "cdragon")]
             cdragon_server: "https://raw.communitydragon.org".to_owned(),
         }
@@ -127,6 +149,14 @@ impl AsyncClientBuilder {
         self
     }
 
+    /// Configures which responses get cached, and for how long. Only applies
+    /// when a cache directory is specified. Uses [CachePolicy::default] if not
+    /// specified.
+    pub fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.cache_policy = policy;
+        self
+    }
+
     /// Configure the ddragon version for making requests. Normally this should
     /// not be needed, as the latest version is always used.
     pub fn version(mut self, version: &str) -> Self {
@@ -134,6 +164,99 @@ impl AsyncClientBuilder {
         self
     }
 
+    /// Configure the language data is requested in. Defaults to
+    /// [Locale::EnUs].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Configures the client to only ever serve data from the cache. The most
+    /// recently fetched version list is used to pick the latest version, and
+    /// any request for data that isn't cached fails with
+    /// [ClientError::NotCached].
+    ///
+    /// This requires a cache directory or a custom source, and building fails
+    /// with [ClientError::OfflineWithoutCache] otherwise. Agents provided with
+    /// [AsyncClientBuilder::agent_with_middleware] must include an
+    /// [AsyncCacheMiddleware] configured with [AsyncCacheMiddleware::offline].
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Configures when failed requests are retried, for both data and images.
+    /// Requests aren't retried unless this is set. Doesn't apply to a custom
+    /// [DataSource].
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{AsyncClientBuilder, retry::RetryPolicy};
+    ///
+    /// let policy = RetryPolicy::default();
+    /// let client = AsyncClientBuilder::new().retry_policy(policy).build().await.unwrap();
+    /// # })
+    /// ```
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Configures the client to collect every field it receives that the
+    /// models don't know about, e.g. after Riot adds a field, instead of
+    /// silently dropping them. See [AsyncClient::drift_report].
+    pub fn report_drift(mut self, report_drift: bool) -> Self {
+        self.report_drift = report_drift;
+        self
+    }
+
+    /// Sets how many requests bulk methods like
+    /// [AsyncClient::champions_detailed] make at once. Defaults to 8, and is
+    /// at least 1.
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// Configures the client to keep up to `entries` parsed resources in
+    /// memory, which are returned instead of reading and deserializing them
+    /// again. The least recently used resource is dropped to make room for
+    /// another. Only versioned ddragon data that the
+    /// [cache policy](Self::cache_policy) treats as immutable is kept, so the
+    /// version list and cdragon data are still always fetched.
+    ///
+    /// This limits the number of resources, not the memory they use: the
+    /// whole of [AsyncClient::champions_full] takes up a single entry, the
+    /// same as one champion.
+    ///
+    /// [AsyncClient::fetch_shared] returns memoized data without copying it.
+    /// Methods like [AsyncClient::champions] and [AsyncClient::items] return a
+    /// clone of it, which still skips reading and deserializing the data.
+    pub fn memoize(mut self, entries: usize) -> Self {
+        self.memoize = Some(entries);
+        self
+    }
+
+    /// Configures the client to read everything from a [DataSource] instead
+    /// of the network, e.g. an extracted dragontail archive with
+    /// [DirectorySource](crate::source::DirectorySource). Any agent and
+    /// caching options are ignored.
+    ///
+    /// The source is run on tokio's blocking thread pool with
+    /// [BlockingSource], so it may block.
+    pub fn source(mut self, source: impl DataSource + 'static) -> Self {
+        self.source = Some(Arc::new(BlockingSource::new(source)));
+        self
+    }
+
+    /// Configures the client to read everything from an [AsyncDataSource],
+    /// as with [AsyncClientBuilder::source]. Any agent and caching options
+    /// are ignored.
+    pub fn async_source(mut self, source: impl AsyncDataSource + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
     #[cfg(test)]
     fn server(mut self, server: &str) -> Self {
         self.server = server.to_owned();
@@ -147,65 +270,70 @@ impl AsyncClientBuilder {
     /// - If a custom [Client] is specified, not specifying a cache directory will
     ///   result in no content being cached.
     pub async fn build(self) -> Result<AsyncClient, ClientError> {
-        let agent = match self.agent {
-            Some(a) => a,
-            None => ClientAgent::Plain(Client::new()),
+        let memo_policy = self.cache_policy.clone();
+        let base_url = Url::parse(&self.server)?;
+        #[cfg(feature = "cdragon")]
+        let cdragon_base_url = Url::parse(&self.cdragon_server)?;
+
+        // The cache directory is only known to be in use if we set up the
+        // middleware ourselves.
+        let cache = match (&self.agent, &self.source) {
+            (None | Some(ClientAgent::Plain(_)), None) => self.cache,
+            _ => None,
+        };
+        // Without the middleware, nothing would stop requests reaching the
+        // network.
+        let custom_agent = matches!(self.agent, Some(ClientAgent::Middleware(_)));
+        if self.offline && cache.is_none() && !custom_agent && self.source.is_none() {
+            return Err(ClientError::OfflineWithoutCache);
+        }
+        let source = match self.source {
+            Some(source) => source,
+            None => {
+                let agent = match self.agent.unwrap_or_else(|| ClientAgent::Plain(Client::new())) {
+                    ClientAgent::Plain(plain_agent) => match &cache {
+                        Some(cache_dir) => {
+                            let middleware = AsyncCacheMiddleware::new(cache_dir)
+                                .policy(self.cache_policy)
+                                .offline(self.offline);
+                            MiddlewareClientBuilder::new(plain_agent).with(middleware).build()
+                        }
+                        None => MiddlewareClientBuilder::new(plain_agent).build(),
+                    },
+                    ClientAgent::Middleware(middleware_agent) => middleware_agent,
+                };
+                let source = AsyncHttpSource::new(agent)
+                    .offline(self.offline)
+                    .retry(self.retry_policy)
+                    .server(base_url.clone());
+                #[cfg(feature = "cdragon")]
+                let source = source.cdragon_server(cdragon_base_url.clone());
+                Arc::new(source)
+            }
         };
 
-        let base_url = Url::parse(&self.server)?;
+        let client = AsyncClient {
+            source,
+            cache,
+            version: String::new(),
+            locale: self.locale,
+            drift: self.report_drift.then(Default::default),
+            concurrency: self.concurrency,
+            memo: self.memoize.map(|entries| Arc::new(Memo::new(entries, memo_policy))),
+            base_url,
+            #[cfg(feature = "cdragon")]
+            cdragon_base_url,
+        };
 
         let latest_version = if let Some(version) = self.version {
             version
         } else {
-            let version_list = match agent.clone() {
-                ClientAgent::Plain(a) => {
-                    a.get(base_url.join("/api/versions.json")?.as_str())
-                        .send()
-                        .await?
-                        .json::<Vec<String>>()
-                        .await?
-                }
-                ClientAgent::Middleware(a) => {
-                    a.get(base_url.join("/api/versions.json")?.as_str())
-                        .send()
-                        .await?
-                        .json::<Vec<String>>()
-                        .await?
-                }
-            };
+            let version_list = client.get_data::<Vec<String>>(Resource::Versions).await?;
 
             version_list.first().ok_or(ClientError::NoLatestVersion)?.to_owned()
         };
 
-        let middleware_agent = match agent {
-            ClientAgent::Plain(plain_agent) => match self.cache {
-                Some(cache_dir) => MiddlewareClientBuilder::new(plain_agent)
-                    .with(Cache(HttpCache {
-                        mode: CacheMode::ForceCache,
-                        manager: CACacheManager { path: cache_dir.into() },
-                        options: HttpCacheOptions::default(),
-                    }))
-                    .build(),
-                None => MiddlewareClientBuilder::new(plain_agent).build(),
-            },
-            ClientAgent::Middleware(middleware_agent) => middleware_agent,
-        };
-
-        Ok(AsyncClient {
-            agent: middleware_agent,
-            #[cfg(feature = "cdragon")]
-            cdragon_version: latest_version
-                .clone()
-                .split('.')
-                .take(2)
-                .map(|p| p.to_owned())
-                .collect::<Vec<String>>()
-                .join("."),
-            version: latest_version,
-            base_url,
-            #[cfg(feature = "cdragon")]
-            cdragon_base_url: Url::parse(&self.cdragon_server)?,
-        })
+        Ok(client.at_version(&latest_version))
     }
 }
 
@@ -218,19 +346,23 @@ impl Default for AsyncClientBuilder {
 #[derive(Clone)]
 /// Provides access to the ddragon API.
 pub struct AsyncClient {
-    agent: ClientWithMiddleware,
+    source: Arc<dyn AsyncDataSource>,
+    cache: Option<String>,
     /// The current version of the API data reported back to us from the API.
     pub version: String,
+    /// The language used for any localized data.
+    pub locale: Locale,
+    drift: Option<Arc<Mutex<DriftReport>>>,
+    concurrency: usize,
+    memo: Option<Arc<Memo>>,
     base_url: Url,
 
     #[cfg(feature = "cdragon")]
     cdragon_base_url: Url,
-    #[cfg(feature = "cdragon")]
-    cdragon_version: String,
 }
 
 macro_rules! create_endpoint {
-    ($name:ident, $kind:literal, $path:literal, $ret:ty) => {
+    ($name:ident, $endpoint:ident, $kind:literal, $ret:ty) => {
         #[doc = concat!(" Returns ", $kind, " data.")]
         #[doc = ""]
         #[doc = " ```no_run"]
@@ -242,11 +374,17 @@ macro_rules! create_endpoint {
         #[doc = " # })"]
         #[doc = " ```"]
         pub async fn $name(&self) -> Result<$ret, ClientError> {
-            self.get_data_ddragon::<$ret>(concat!("./", $path, ".json")).await
+            self.fetch_memoized(&endpoint::$endpoint).await
         }
     };
 }
 
+macro_rules! create_endpoints {
+    ($(($name:ident, $endpoint:ident, $kind:literal, $path:literal, $ret:ty)),+ $(,)?) => {
+        $(create_endpoint!($name, $endpoint, $kind, $ret);)+
+    };
+}
+
 impl AsyncClient {
     /// Creates a new client with the specified directory as the caching location
     /// for any data the client downloads.
@@ -262,60 +400,212 @@ impl AsyncClient {
         AsyncClientBuilder::new().cache(cache_dir).build().await
     }
 
-    fn get_data_url(&self) -> Result<Url, url::ParseError> {
-        self.base_url.join(&format!("/cdn/{}/data/en_US/", &self.version))
+    /// Returns a copy of this client that requests localized data in a
+    /// different language. The underlying agent (and therefore the cache) is
+    /// shared with the original client.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{AsyncClient, models::Locale};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champions = api.with_locale(Locale::KoKr).champions().await.unwrap();
+    /// # })
+    /// ```
+    pub fn with_locale(&self, locale: Locale) -> Self {
+        Self { locale, ..self.clone() }
     }
 
-    #[cfg(feature = "cdragon")]
-    fn get_cdragon_data_url(&self) -> Result<Url, url::ParseError> {
-        self.cdragon_base_url.join(&format!("/{}/cdragon/", &self.cdragon_version))
+    /// Returns a copy of this client that requests data for a different
+    /// ddragon version. The underlying agent (and therefore the cache) and the
+    /// locale are shared with the original client, so this is cheap enough to
+    /// do for every historical patch you need.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let old_items = api.at_version("13.24.1").items().await.unwrap();
+    /// let new_items = api.items().await.unwrap();
+    /// # })
+    /// ```
+    pub fn at_version(&self, version: &str) -> Self {
+        Self { version: version.to_owned(), ..self.clone() }
     }
 
-    async fn get_data<T: DeserializeOwned>(&self, request_url: &str) -> Result<T, ClientError> {
-        self.agent.get(request_url).send().await?.json::<T>().await.map_err(|e| e.into())
+    /// Returns every field received so far that the models don't know about,
+    /// if [AsyncClientBuilder::report_drift] is enabled. The report is shared with any
+    /// copies of this client, e.g. from [AsyncClient::at_version].
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClientBuilder;
+    ///
+    /// let api = AsyncClientBuilder::new().report_drift(true).build().await.unwrap();
+    /// let items = api.items().await.unwrap();
+    /// if let Some(drift) = api.drift_report().filter(|d| !d.is_empty()) {
+    ///     println!("Unknown fields:\n{drift}");
+    /// }
+    /// # })
+    /// ```
+    pub fn drift_report(&self) -> Option<DriftReport> {
+        self.drift.as_ref().map(|drift| drift.lock().unwrap().clone())
     }
 
-    async fn get_data_ddragon<T: DeserializeOwned>(
-        &self,
-        endpoint: &str,
-    ) -> Result<T, ClientError> {
-        let joined_url = self.get_data_url()?.join(endpoint)?;
-        let request_url = joined_url.as_str();
+    fn get_url(&self, resource: &Resource) -> Result<Url, url::ParseError> {
+        match resource {
+            #[cfg(feature = "cdragon")]
+            Resource::CDragon { .. } => self.cdragon_base_url.join(&resource.path()),
+            _ => self.base_url.join(&resource.path()),
+        }
+    }
 
-        self.get_data(request_url).await
+    #[cfg(test)]
+    fn data_resource(&self, path: &str) -> Resource {
+        endpoint::data_resource(&self.version, &self.locale, path)
     }
 
-    #[cfg(feature = "cdragon")]
-    async fn get_data_cdragon<T: DeserializeOwned>(
-        &self,
-        endpoint: &str,
-    ) -> Result<T, ClientError> {
-        let joined_url = self.get_cdragon_data_url()?.join(endpoint)?;
-        let request_url = joined_url.as_str();
-
-        self.get_data(request_url).await
-    }
-
-    create_endpoint!(challenges, "challenge", "challenges", Challenges);
-    create_endpoint!(champions, "champion", "champion", Champions);
-    create_endpoint!(champions_full, "complete champion", "championFull", ChampionsFull);
-    create_endpoint!(items, "item", "item", Items);
-    create_endpoint!(maps, "map", "map", Maps);
-    create_endpoint!(mission_assets, "mission asset", "mission-assets", MissionAssets);
-    create_endpoint!(profile_icons, "profile icon", "profileicon", ProfileIcons);
-    create_endpoint!(runes, "rune", "runesReforged", Runes);
-    create_endpoint!(spell_buffs, "spell buff", "spellbuffs", SpellBuffs);
-    create_endpoint!(summoner_spells, "summoner_spells", "summoner", SummonerSpells);
-    create_endpoint!(translations, "translation", "language", Translations);
-    create_endpoint!(tft_arenas, "TFT arena", "tft-arena", Arenas);
-    create_endpoint!(tft_augments, "TFT augment", "tft-augments", Augments);
-    create_endpoint!(tft_champions, "TFT champion", "tft-champion", tft::Champions);
-    create_endpoint!(tft_hero_augments, "TFT hero augment", "tft-hero-augments", HeroAugments);
-    create_endpoint!(tft_items, "TFT item", "tft-item", tft::Items);
-    create_endpoint!(tft_queues, "TFT queue", "tft-queues", Queues);
-    create_endpoint!(tft_regalia, "TFT regalia", "tft-regalia", Regalia);
-    create_endpoint!(tft_tacticians, "TFT tactician", "tft-tactician", Tacticians);
-    create_endpoint!(tft_traits, "TFT trait", "tft-trait", Traits);
+    async fn fetch_resource(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        self.source.fetch(resource).await
+    }
+
+    async fn get_data<T: DeserializeOwned>(&self, resource: Resource) -> Result<T, ClientError> {
+        let data = self.fetch_resource(&resource).await?;
+        let url = || self.get_url(&resource).map_or_else(|_| resource.path(), |u| u.to_string());
+        endpoint::deserialize(&data, url, self.drift.as_deref())
+    }
+
+    /// Returns the data for any [Endpoint], using this client's version,
+    /// locale and cache. Useful for data files this crate doesn't have a
+    /// method for yet, through [DataEndpoint](endpoint::DataEndpoint).
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{AsyncClient, endpoint::{DataEndpoint, Items}, models};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let items = api.fetch(&Items).await.unwrap();
+    /// let maps = api.fetch(&DataEndpoint::<models::Maps>::new("map.json")).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn fetch<E: Endpoint>(&self, endpoint: &E) -> Result<E::Output, ClientError> {
+        endpoint.output(self.get_data(endpoint.resource(&self.version, &self.locale)).await?)
+    }
+
+    /// Returns the data for any [Endpoint] behind an [Arc], which is shared
+    /// with later calls for the same data if [AsyncClientBuilder::memoize] is
+    /// enabled. This skips reading and deserializing data that was already
+    /// parsed, so suits hot paths.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{AsyncClientBuilder, endpoint::Champions};
+    ///
+    /// let api = AsyncClientBuilder::new().memoize(64).build().await.unwrap();
+    /// let champions = api.fetch_shared(&Champions).await.unwrap();
+    /// let again = api.fetch_shared(&Champions).await.unwrap();
+    /// assert!(std::sync::Arc::ptr_eq(&champions, &again));
+    /// # })
+    /// ```
+    pub async fn fetch_shared<E>(&self, endpoint: &E) -> Result<Arc<E::Output>, ClientError>
+    where
+        E: Endpoint + 'static,
+        E::Output: Send + Sync + 'static,
+    {
+        let resource = endpoint.resource(&self.version, &self.locale);
+        let Some(memo) = &self.memo else {
+            return Ok(Arc::new(endpoint.output(self.get_data(resource).await?)?));
+        };
+        if let Some(output) = memo.get::<E, _>(&resource) {
+            return Ok(output);
+        }
+
+        let output = Arc::new(endpoint.output(self.get_data(resource.clone()).await?)?);
+        memo.insert::<E, _>(resource, output.clone());
+        Ok(output)
+    }
+
+    /// Returns the data for an [Endpoint], cloned from the memo if
+    /// [AsyncClientBuilder::memoize] is enabled. The typed methods like
+    /// [AsyncClient::champions] use this, so they still skip deserializing data
+    /// that was already parsed.
+    async fn fetch_memoized<E>(&self, endpoint: &E) -> Result<E::Output, ClientError>
+    where
+        E: Endpoint + 'static,
+        E::Output: Clone + Send + Sync + 'static,
+    {
+        match &self.memo {
+            Some(_) => Ok(E::Output::clone(&*self.fetch_shared(endpoint).await?)),
+            None => self.fetch(endpoint).await,
+        }
+    }
+
+    /// Returns the raw bytes behind any [Endpoint], without deserializing
+    /// them.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{AsyncClient, endpoint::DataEndpoint};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let raw = api.fetch_raw(&DataEndpoint::<()>::new("feats.json")).await.unwrap();
+    /// println!("{}", String::from_utf8_lossy(&raw));
+    /// # })
+    /// ```
+    pub async fn fetch_raw<E: Endpoint>(&self, endpoint: &E) -> Result<Vec<u8>, ClientError> {
+        self.fetch_resource(&endpoint.resource(&self.version, &self.locale)).await
+    }
+
+    data_endpoints!(create_endpoints);
+
+    /// Returns every version ddragon has published, newest first.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let versions = api.versions().await.unwrap();
+    /// let latest_14_3 = versions.latest_for(14, 3);
+    /// # })
+    /// ```
+    pub async fn versions(&self) -> Result<Versions, ClientError> {
+        self.fetch(&endpoint::Versions).await
+    }
+
+    /// Returns a copy of this client pinned to the ddragon version matching a
+    /// game version, as reported in match data. The newest ddragon version for
+    /// the same patch is used, e.x. `14.3.558.2130` resolves to `14.3.1`.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let api_14_3 = api.version_for_game("14.3.558.2130").await.unwrap();
+    /// # })
+    /// ```
+    pub async fn version_for_game(&self, game_version: &str) -> Result<Self, ClientError> {
+        let versions = self.versions().await?;
+        let version =
+            versions.for_game_version(game_version).ok_or(ClientError::NoMatchingVersion)?;
+        Ok(self.at_version(&version.to_string()))
+    }
+
+    /// Returns the list of languages ddragon publishes data in.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let languages = api.languages().await.unwrap();
+    /// # })
+    /// ```
+    pub async fn languages(&self) -> Result<Vec<Locale>, ClientError> {
+        self.fetch(&endpoint::Languages).await
+    }
 
     /// Returns data for a single champion. The champion's name or numeric key
     /// should not be used here -- this should be the key property on the
@@ -331,24 +621,131 @@ impl AsyncClient {
     /// # })
     /// ```
     pub async fn champion(&self, key: &str) -> Result<Champion, ClientError> {
-        self.get_data_ddragon::<ChampionWrapper>(&format!("./champion/{key}.json"))
-            .await?
-            .data
-            .get(key)
-            .cloned()
-            .ok_or(ClientError::NoChampionData)
+        self.fetch_memoized(&endpoint::Champion::new(key)).await
     }
 
-    #[cfg(feature = "image")]
-    async fn get_image(&self, path: Url) -> Result<DynamicImage, ClientError> {
-        let response = self
-            .agent
-            .get(path.as_str())
-            .send()
+    /// Returns data for many champions at once, making up to
+    /// [AsyncClientBuilder::concurrency] requests at a time. Champions that
+    /// fail don't stop the rest, and are listed in the returned report instead.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let report = api.champions_detailed(&["Ahri", "MonkeyKing"]).await;
+    /// let ahri = &report.fetched["Ahri"];
+    /// # })
+    /// ```
+    pub async fn champions_detailed(&self, keys: &[&str]) -> BatchReport<Champion> {
+        stream::iter(keys)
+            .map(|key| async move { (key.to_string(), self.champion(key).await) })
+            .buffer_unordered(self.concurrency)
+            .collect::<Vec<_>>()
             .await
-            .map_err(std::convert::Into::<ClientError>::into)?;
+            .into_iter()
+            .collect()
+    }
 
-        load_from_memory(&response.bytes().await?).map_err(|e| e.into())
+    /// Returns data for every champion, as with
+    /// [AsyncClient::champions_detailed]. Only fails if the list of champions
+    /// can't be fetched.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let report = api.all_champions_detailed().await.unwrap();
+    /// assert!(report.is_complete());
+    /// # })
+    /// ```
+    pub async fn all_champions_detailed(&self) -> Result<BatchReport<Champion>, ClientError> {
+        let champions = self.champions().await?;
+        let keys: Vec<&str> = champions.data.keys().map(String::as_str).collect();
+        Ok(self.champions_detailed(&keys).await)
+    }
+
+    /// Downloads everything covered by a [PrefetchPlan] for this client's
+    /// version and locale, so it can be served from the cache later (e.g. by
+    /// an offline client). Anything already cached is skipped, and
+    /// `on_progress` is called after each resource is handled.
+    ///
+    /// Failed downloads don't stop the prefetch, and are listed in the
+    /// returned report instead. This includes the data listing champions,
+    /// items and summoner spells, when it is needed to find their images.
+    ///
+    /// Without a cache directory, e.g. with a custom agent, downloads are
+    /// reported as [uncached](crate::prefetch::PrefetchStatus::Uncached), as
+    /// the client can't tell whether they were stored anywhere.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{AsyncClient, prefetch::PrefetchPlan};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let report = api
+    ///     .prefetch(PrefetchPlan::everything(), |progress| {
+    ///         println!("{}/{} {}", progress.completed, progress.total, progress.url);
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// assert!(report.is_complete());
+    /// # })
+    /// ```
+    pub async fn prefetch(
+        &self,
+        plan: PrefetchPlan,
+        mut on_progress: impl FnMut(PrefetchProgress<'_>),
+    ) -> Result<PrefetchReport, ClientError> {
+        // Listings are downloaded up front to find what else to fetch.
+        let mut listings = Listings::default();
+        if plan.needs_champions() {
+            listings.champions = Some(self.prefetch_listing(&endpoint::ChampionsFull).await?);
+        }
+        if plan.needs_images() {
+            listings.items = Some(self.prefetch_listing(&endpoint::Items).await?);
+            listings.summoner_spells =
+                Some(self.prefetch_listing(&endpoint::SummonerSpells).await?);
+        }
+
+        let stored = self.cache.is_some();
+        let mut queue =
+            PrefetchQueue::new(plan, &self.version, &self.locale, listings, stored, |resource| {
+                self.get_url(resource)
+            })?;
+        for (url, resource, listed) in queue.take_pending() {
+            let result = match listed {
+                Some(fetched) => Ok(fetched),
+                None if self.is_cached(&url).await => Ok(false),
+                None => self.fetch_resource(&resource).await.map(|_| true),
+            };
+            queue.record(url, result, &mut on_progress);
+        }
+
+        Ok(queue.into_report())
+    }
+
+    async fn prefetch_listing<E: Endpoint>(
+        &self,
+        endpoint: &E,
+    ) -> Result<Listing<E::Output>, ClientError> {
+        let resource = endpoint.resource(&self.version, &self.locale);
+        let cached = self.is_cached(self.get_url(&resource)?.as_str()).await;
+        let data = self.fetch(endpoint).await.map(|data| (data, !cached));
+        Ok(Listing { resource, data })
+    }
+
+    async fn is_cached(&self, request_url: &str) -> bool {
+        match &self.cache {
+            Some(dir) => cacache::metadata(dir, request_url).await.ok().flatten().is_some(),
+            None => false,
+        }
+    }
+
+    #[cfg(feature = "image")]
+    async fn get_image(&self, resource: Resource) -> Result<DynamicImage, ClientError> {
+        load_from_memory(&self.fetch_resource(&resource).await?).map_err(|e| e.into())
     }
 
     /// Returns an [image::DynamicImage].
@@ -365,12 +762,8 @@ impl AsyncClient {
     #[cfg(feature = "image")]
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub async fn image_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.image_path()
-        ))?)
-        .await
+        self.get_image(Resource::Image { version: self.version.clone(), path: item.image_path() })
+            .await
     }
 
     /// Returns an [image::DynamicImage].
@@ -390,37 +783,35 @@ impl AsyncClient {
     #[cfg(feature = "image")]
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub async fn sprite_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.sprite_path()
-        ))?)
-        .await
+        self.get_image(Resource::Image { version: self.version.clone(), path: item.sprite_path() })
+            .await
     }
 
     /// [cdragon] Returns augment data for the Arena game mode.
     ///
     /// ```no_run
-    /// use ddragon::Client;
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
     ///
-    /// let api = Client::new("./cache").unwrap();
-    /// let arena_augments = api.arena_augments().unwrap();
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let arena_augments = api.arena_augments().await.unwrap();
+    /// # })
     /// ```
     #[cfg(feature = "cdragon")]
     #[cfg_attr(docsrs, doc(cfg(feature = "cdragon")))]
-    pub async fn arena_augments(&self) -> Result<Vec<crate::models::Augment>, ClientError> {
-        self.get_data_cdragon::<crate::models::cdragon::augments::AugmentsResponse>(
-            "./arena/en_us.json",
-        )
-        .await
-        .map(|r| r.augments)
+    pub async fn arena_augments(&self) -> Result<Vec<models::Augment>, ClientError> {
+        self.fetch_memoized(&endpoint::ArenaAugments).await
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use mockito::{Server, ServerGuard};
+    use crate::endpoint::DATA_ENDPOINTS;
+    use crate::fixtures::champion_json;
+    use crate::source::MemorySource;
+    use mockito::{Matcher, Server, ServerGuard};
+    use std::{env::temp_dir, fs::remove_dir_all, time::Duration};
 
     async fn create_mock_client() -> (ServerGuard, String, AsyncClient) {
         let server = Server::new_async().await;
@@ -430,12 +821,18 @@ mod test {
             server,
             url.clone(),
             AsyncClient {
-                agent: MiddlewareClientBuilder::new(Client::new()).build(),
+                source: Arc::new(
+                    AsyncHttpSource::new(MiddlewareClientBuilder::new(Client::new()).build())
+                        .server(parsed.clone()),
+                ),
+                cache: None,
                 version: "0.0.0".to_owned(),
+                locale: Locale::EnUs,
+                drift: None,
+                concurrency: 8,
+                memo: None,
                 #[cfg(feature = "cdragon")]
                 cdragon_base_url: parsed.clone(),
-                #[cfg(feature = "cdragon")]
-                cdragon_version: "0.0".to_owned(),
                 base_url: parsed,
             },
         )
@@ -533,24 +930,145 @@ mod test {
             assert!(maybe_client.is_ok());
             assert_eq!(maybe_client.unwrap().version, "3.3.3");
         }
+
+        #[tokio::test]
+        async fn result_ok_offline_from_cached_versions() {
+            let mut server = Server::new_async().await;
+            let mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["1.1.1", "0.0.0"]"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let cache_dir = temp_dir().join("async-client-offline01");
+            let _ = remove_dir_all(&cache_dir);
+            let cache_dir = cache_dir.to_string_lossy();
+
+            let _ = AsyncClientBuilder::new()
+                .server(&server.url())
+                .cache(&cache_dir)
+                .build()
+                .await
+                .unwrap();
+            let offline_client = AsyncClientBuilder::new()
+                .server(&server.url())
+                .cache(&cache_dir)
+                .offline(true)
+                .build()
+                .await;
+
+            assert_eq!(offline_client.unwrap().version, "1.1.1");
+            mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn result_ok_from_memory_source() {
+            let source = MemorySource::new()
+                .with(Resource::Versions, r#"["14.3.1", "14.2.1"]"#)
+                .with(Resource::Languages, r#"["en_US", "ko_KR"]"#);
+
+            let client = AsyncClientBuilder::new()
+                .server("https://a-very-fake.urltogoto")
+                .source(source)
+                .build()
+                .await
+                .unwrap();
+
+            assert_eq!(client.version, "14.3.1");
+            assert_eq!(client.languages().await.unwrap(), vec![Locale::EnUs, Locale::KoKr]);
+            assert!(matches!(client.items().await, Err(ClientError::NotFound { .. })));
+        }
+
+        #[tokio::test]
+        async fn result_err_offline_without_cached_versions() {
+            let cache_dir = temp_dir().join("async-client-offline02");
+            let _ = remove_dir_all(&cache_dir);
+
+            let maybe_client = AsyncClientBuilder::new()
+                .server("https://dead-server.notadomain")
+                .cache(&cache_dir.to_string_lossy())
+                .offline(true)
+                .build()
+                .await;
+
+            assert!(matches!(maybe_client, Err(ClientError::NotCached { .. })));
+        }
+
+        #[tokio::test]
+        async fn result_err_offline_without_cache() {
+            let maybe_client = AsyncClientBuilder::new()
+                .server("https://dead-server.notadomain")
+                .offline(true)
+                .build()
+                .await;
+
+            assert!(matches!(maybe_client, Err(ClientError::OfflineWithoutCache)));
+        }
+
+        #[tokio::test]
+        async fn result_ok_manual_locale() {
+            let maybe_client =
+                AsyncClientBuilder::new().version("0.0.0").locale(Locale::PtBr).build().await;
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().locale, Locale::PtBr);
+        }
     }
 
     mod requests {
         use super::*;
 
         #[tokio::test]
-        async fn get_data_url_constructs_expected_baseurl() {
+        async fn get_url_constructs_expected_data_url() {
+            let (_server, url, client) = create_mock_client().await;
+            assert_eq!(
+                client.get_url(&client.data_resource("./item.json")).unwrap().as_str(),
+                format!("{url}/cdn/0.0.0/data/en_US/item.json")
+            );
+        }
+
+        #[tokio::test]
+        async fn data_resource_uses_configured_locale() {
             let (_server, url, client) = create_mock_client().await;
+            let client = client.with_locale(Locale::DeDe);
             assert_eq!(
-                client.get_data_url().unwrap().as_str(),
-                format!("{url}/cdn/0.0.0/data/en_US/")
+                client.get_url(&client.data_resource("item.json")).unwrap().as_str(),
+                format!("{url}/cdn/0.0.0/data/de_DE/item.json")
+            );
+        }
+
+        #[tokio::test]
+        async fn data_resource_uses_pinned_version() {
+            let (_server, url, client) = create_mock_client().await;
+            let pinned = client.at_version("14.3.1");
+            assert_eq!(
+                pinned.get_url(&pinned.data_resource("item.json")).unwrap().as_str(),
+                format!("{url}/cdn/14.3.1/data/en_US/item.json")
+            );
+            assert_eq!(client.version, "0.0.0");
+        }
+
+        #[cfg(feature = "cdragon")]
+        #[tokio::test]
+        async fn get_url_uses_pinned_cdragon_patch() {
+            let (_server, url, client) = create_mock_client().await;
+            let pinned = client.at_version("14.3.1");
+            let resource = endpoint::ArenaAugments.resource(&pinned.version, &pinned.locale);
+            assert_eq!(
+                pinned.get_url(&resource).unwrap().as_str(),
+                format!("{url}/14.3/cdragon/arena/en_us.json")
             );
         }
 
         #[tokio::test]
         async fn get_data_err_if_server_unavailable() {
             let (_server, _url, client) = create_mock_client().await;
-            assert!(client.get_data::<String>("/fake-endpoint").await.is_err());
+            assert!(
+                client.get_data::<String>(client.data_resource("fake-endpoint")).await.is_err()
+            );
         }
 
         #[tokio::test]
@@ -564,7 +1082,158 @@ mod test {
                 .create_async()
                 .await;
 
-            assert!(client.get_data::<String>("./data.json").await.is_err());
+            assert!(matches!(
+                client.get_data::<String>(client.data_resource("data.json")).await,
+                Err(ClientError::Deserialize { .. })
+            ));
+        }
+
+        #[tokio::test]
+        async fn champion_err_not_found_for_unknown_key() {
+            let (mut server, url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/Foo.json")
+                .with_status(404)
+                .create_async()
+                .await;
+
+            assert!(matches!(
+                client.champion("Foo").await,
+                Err(ClientError::NotFound { url: failed_url })
+                    if failed_url == format!("{url}/cdn/0.0.0/data/en_US/champion/Foo.json")
+            ));
+        }
+
+        #[tokio::test]
+        async fn champions_detailed_ok_with_partial_results() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _ahri = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/Ahri.json")
+                .with_status(200)
+                .with_body(champion_json("Ahri"))
+                .create_async()
+                .await;
+            let _foo = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/Foo.json")
+                .with_status(404)
+                .create_async()
+                .await;
+
+            let client = AsyncClient { concurrency: 1, ..client };
+            let report = client.champions_detailed(&["Ahri", "Foo"]).await;
+
+            assert_eq!(report.fetched["Ahri"].name, "Ahri");
+            assert!(matches!(report.failed["Foo"], ClientError::NotFound { .. }));
+            assert!(!report.is_complete());
+        }
+
+        #[tokio::test]
+        async fn fetch_shared_ok_reuses_memoized_data() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/feats.json")
+                .with_status(200)
+                .with_body(r#"{"a": 1}"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let client = AsyncClient {
+                memo: Some(Arc::new(Memo::new(1, CachePolicy::default()))),
+                ..client
+            };
+            let endpoint = endpoint::DataEndpoint::<serde_json::Value>::new("feats.json");
+            let first = client.fetch_shared(&endpoint).await.unwrap();
+            let second = client.at_version("0.0.0").fetch_shared(&endpoint).await.unwrap();
+
+            assert!(Arc::ptr_eq(&first, &second));
+            mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn typed_methods_ok_reuse_memoized_data() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/map.json")
+                .with_status(200)
+                .with_body(r#"{"version": "0.0.0", "data": {}}"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let client = AsyncClient {
+                memo: Some(Arc::new(Memo::new(1, CachePolicy::default()))),
+                ..client
+            };
+
+            assert_eq!(client.maps().await.unwrap(), client.maps().await.unwrap());
+            mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn get_data_err_includes_status() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/item.json")
+                .with_status(503)
+                .create_async()
+                .await;
+
+            assert!(matches!(
+                client.items().await,
+                Err(ClientError::HttpStatus { status: 503, .. })
+            ));
+        }
+
+        #[tokio::test]
+        async fn versions_ok_deserializes_to_patch_versions() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["14.3.1", "14.2.1", "lolpatch_3.7"]"#)
+                .create_async()
+                .await;
+
+            let versions = client.versions().await.unwrap();
+            assert_eq!(versions.len(), 3);
+            assert_eq!(versions.latest().unwrap().to_string(), "14.3.1");
+        }
+
+        #[tokio::test]
+        async fn version_for_game_pins_matching_version() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["14.4.1", "14.3.1", "14.3.0"]"#)
+                .create_async()
+                .await;
+
+            assert_eq!(client.version_for_game("14.3.558.2130").await.unwrap().version, "14.3.1");
+            assert!(matches!(
+                client.version_for_game("13.1.1.1").await,
+                Err(ClientError::NoMatchingVersion)
+            ));
+        }
+
+        #[tokio::test]
+        async fn languages_ok_deserializes_to_locales() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/languages.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["en_US", "ko_KR", "xx_XX"]"#)
+                .create_async()
+                .await;
+
+            assert_eq!(
+                client.languages().await.unwrap(),
+                vec![Locale::EnUs, Locale::KoKr, Locale::Other("xx_XX".to_owned())]
+            );
         }
 
         #[tokio::test]
@@ -579,9 +1248,169 @@ mod test {
                 .await;
 
             assert_eq!(
-                client.get_data_ddragon::<Vec<String>>("./data.json").await.unwrap(),
+                client.get_data::<Vec<String>>(client.data_resource("data.json")).await.unwrap(),
+                vec!["value".to_owned()]
+            );
+        }
+
+        #[tokio::test]
+        async fn fetch_ok_for_custom_endpoints() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/feats.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"data": {"1": "First Blood"}}"#)
+                .create_async()
+                .await;
+
+            let endpoint = endpoint::DataEndpoint::<serde_json::Value>::new("feats.json");
+            assert_eq!(client.fetch(&endpoint).await.unwrap()["data"]["1"], "First Blood");
+            assert_eq!(
+                client.fetch_raw(&endpoint).await.unwrap(),
+                br#"{"data": {"1": "First Blood"}}"#.to_vec()
+            );
+        }
+
+        #[tokio::test]
+        async fn get_data_retries_transient_failures() {
+            let mut server = Server::new_async().await;
+            let unavailable = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(503)
+                .with_header("Retry-After", "0")
+                .expect(2)
+                .create_async()
+                .await;
+            let available = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["value"]"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let policy = RetryPolicy::default().backoff(Duration::from_secs(60), Duration::MAX);
+            let client = AsyncClientBuilder::new()
+                .server(&server.url())
+                .version("0.0.0")
+                .retry_policy(policy)
+                .build()
+                .await
+                .unwrap();
+
+            assert_eq!(
+                client.get_data::<Vec<String>>(client.data_resource("data.json")).await.unwrap(),
                 vec!["value".to_owned()]
             );
+            unavailable.assert_async().await;
+            available.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn prefetch_skips_cached_endpoints() {
+            let mut server = Server::new_async().await;
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/cdn/0\.0\.0/data/en_US/[\w-]+\.json$".to_owned()))
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("{}")
+                .expect(DATA_ENDPOINTS.len())
+                .create_async()
+                .await;
+
+            let cache_dir = temp_dir().join("async-client-prefetch01");
+            let _ = remove_dir_all(&cache_dir);
+            let client = AsyncClientBuilder::new()
+                .server(&server.url())
+                .cache(&cache_dir.to_string_lossy())
+                .version("0.0.0")
+                .build()
+                .await
+                .unwrap();
+
+            let mut progress = vec![];
+            let first =
+                client.prefetch(PrefetchPlan::default(), |p| progress.push(p.completed)).await;
+            let second = client.prefetch(PrefetchPlan::default(), |_| {}).await.unwrap();
+
+            assert_eq!(first.unwrap().fetched.len(), DATA_ENDPOINTS.len());
+            assert_eq!(progress, (1..=DATA_ENDPOINTS.len()).collect::<Vec<_>>());
+            assert_eq!(second.skipped.len(), DATA_ENDPOINTS.len());
+            mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn prefetch_reports_listings() {
+            let mut server = Server::new_async().await;
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(200)
+                .with_body(r#"{"format": "", "version": "", "data": {}, "keys": {}}"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let cache_dir = temp_dir().join("async-client-prefetch02");
+            let _ = remove_dir_all(&cache_dir);
+            let client = AsyncClientBuilder::new()
+                .server(&server.url())
+                .cache(&cache_dir.to_string_lossy())
+                .version("0.0.0")
+                .build()
+                .await
+                .unwrap();
+
+            let plan = PrefetchPlan::default().data(false).champions(true);
+            let mut progress = vec![];
+            let first = client.prefetch(plan, |p| progress.push(p.url.to_owned())).await.unwrap();
+            let second = client.prefetch(plan, |_| {}).await.unwrap();
+
+            let listing = format!("{}/cdn/0.0.0/data/en_US/championFull.json", server.url());
+            assert_eq!(first.fetched, vec![listing.clone()]);
+            assert_eq!(progress, vec![listing.clone()]);
+            assert_eq!(second.skipped, vec![listing]);
+            mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn prefetch_without_cache_reports_uncached() {
+            let (mut server, url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(200)
+                .with_body(r#"{"format": "", "version": "", "data": {}, "keys": {}}"#)
+                .create_async()
+                .await;
+
+            let plan = PrefetchPlan::default().data(false).champions(true);
+            let report = client.prefetch(plan, |_| {}).await.unwrap();
+
+            assert!(report.fetched.is_empty());
+            assert_eq!(
+                report.uncached,
+                vec![format!("{url}/cdn/0.0.0/data/en_US/championFull.json")]
+            );
+            assert!(!report.is_complete());
+        }
+
+        #[tokio::test]
+        async fn prefetch_reports_failed_listings() {
+            let (mut server, url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(404)
+                .create_async()
+                .await;
+
+            let plan = PrefetchPlan::default().data(false).champions(true);
+            let report =
+                client.prefetch(plan, |_| panic!("nothing should be fetched")).await.unwrap();
+
+            assert!(report.fetched.is_empty());
+            assert_eq!(report.failed.len(), 1);
+            assert_eq!(report.failed[0].0, format!("{url}/cdn/0.0.0/data/en_US/championFull.json"));
         }
     }
 }
@@ -0,0 +1,69 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::collections::BTreeMap;
+
+use crate::ClientError;
+
+/// The outcome of fetching many resources at once, such as with
+/// `champions_detailed`. One failure doesn't stop the rest from being
+/// fetched, so this holds whatever succeeded alongside what didn't.
+#[derive(Debug)]
+pub struct BatchReport<T> {
+    /// The data that was fetched, by key.
+    pub fetched: BTreeMap<String, T>,
+    /// The keys that could not be fetched, along with the reason why.
+    pub failed: BTreeMap<String, ClientError>,
+}
+
+impl<T> BatchReport<T> {
+    /// Whether every key was fetched.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl<T> Default for BatchReport<T> {
+    fn default() -> Self {
+        Self { fetched: BTreeMap::new(), failed: BTreeMap::new() }
+    }
+}
+
+impl<T> FromIterator<(String, Result<T, ClientError>)> for BatchReport<T> {
+    fn from_iter<I: IntoIterator<Item = (String, Result<T, ClientError>)>>(iter: I) -> Self {
+        let mut report = Self::default();
+        for (key, result) in iter {
+            match result {
+                Ok(data) => {
+                    report.fetched.insert(key, data);
+                }
+                Err(e) => {
+                    report.failed.insert(key, e);
+                }
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_results_by_key() {
+        let report: BatchReport<u32> = [
+            ("b".to_owned(), Ok(2)),
+            ("a".to_owned(), Ok(1)),
+            ("c".to_owned(), Err(ClientError::NoChampionData)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            report.fetched.into_iter().collect::<Vec<_>>(),
+            [("a".to_owned(), 1), ("b".to_owned(), 2)]
+        );
+        assert!(report.failed.contains_key("c"));
+    }
+}
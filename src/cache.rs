@@ -0,0 +1,330 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use cacache::Integrity;
+use url::Url;
+
+use crate::{
+    ClientError,
+    models::versions::{PatchVersion, patch_of},
+};
+
+/// A single response stored in a cache directory, as written by either
+/// client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    /// The URL the response was fetched from.
+    pub url: String,
+    /// The version in the URL, e.g. `14.3.1`. cdragon URLs only include the
+    /// patch, e.g. `14.3`. Unversioned URLs, like the version list, have none.
+    pub version: Option<String>,
+    /// The rest of the URL path after the version, e.g.
+    /// `data/en_US/item.json`.
+    pub endpoint: String,
+    /// The size of the stored response in bytes.
+    pub size: usize,
+    /// When the response was stored, in unix milliseconds.
+    pub written_at: u128,
+    key: String,
+    integrity: Integrity,
+}
+
+impl CacheEntry {
+    fn from_metadata(directory: &str, metadata: cacache::Metadata) -> Self {
+        // Older versions of the async client prefixed cache keys with the
+        // request method.
+        let url = metadata.key.strip_prefix("GET:").unwrap_or(&metadata.key).to_owned();
+        let path = Url::parse(&url).map(|u| u.path().to_owned()).unwrap_or_default();
+        let (version, endpoint) = split_version(&path);
+        // Entries written without an expected size record it as zero, so the
+        // size is taken from the stored response's file instead.
+        let size = match metadata.size {
+            0 => std::fs::metadata(content_path(directory, &metadata.integrity))
+                .map_or(0, |m| m.len() as usize),
+            size => size,
+        };
+
+        Self {
+            version: version.map(|v| v.to_owned()),
+            endpoint: endpoint.to_owned(),
+            url,
+            size,
+            written_at: metadata.time,
+            key: metadata.key,
+            integrity: metadata.integrity,
+        }
+    }
+
+    /// The `major.minor` patch the entry belongs to.
+    pub fn patch(&self) -> Option<(u32, u32)> {
+        let version = self.version.as_deref()?;
+        if let Ok(version) = version.parse::<PatchVersion>() {
+            return Some((version.major, version.minor));
+        }
+        let (major, minor) = patch_of(version).split_once('.')?;
+        Some((major.parse().ok()?, minor.parse().ok()?))
+    }
+}
+
+/// Where cacache stores the content for `integrity`, following its
+/// `content-v2/<algorithm>/<xx>/<yy>/<rest of hex>` layout.
+fn content_path(directory: &str, integrity: &Integrity) -> PathBuf {
+    let (algorithm, hex) = integrity.to_hex();
+    [directory, "content-v2", &algorithm.to_string(), &hex[0..2], &hex[2..4], &hex[4..]]
+        .iter()
+        .collect()
+}
+
+/// Splits a path into the version and the endpoint after it, e.g.
+/// `/cdn/14.3.1/img/item/1001.png` or `/14.3/cdragon/arena/en_us.json`.
+fn split_version(path: &str) -> (Option<&str>, &str) {
+    let path = path.trim_start_matches('/');
+    let mut segments = path.splitn(3, '/');
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("cdn"), Some(version), Some(endpoint)) if version.parse::<PatchVersion>().is_ok() => {
+            (Some(version), endpoint)
+        }
+        (Some(version), Some("cdragon"), Some(_)) => (Some(version), &path[version.len() + 1..]),
+        _ => (None, path),
+    }
+}
+
+/// The outcome of [prune].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// The entries that were removed.
+    pub removed: Vec<CacheEntry>,
+    /// How many bytes of stored responses were deleted.
+    pub freed: u64,
+}
+
+/// Lists every entry in a cache directory, ordered by URL. A directory that
+/// doesn't exist yet is treated as empty.
+///
+/// ```no_run
+/// let entries = ddragon::cache::entries("./cache").unwrap();
+/// for entry in entries {
+///     println!("{} ({} bytes)", entry.url, entry.size);
+/// }
+/// ```
+pub fn entries(directory: &str) -> Result<Vec<CacheEntry>, ClientError> {
+    if !Path::new(directory).exists() {
+        return Ok(vec![]);
+    }
+    let mut entries = cacache::list_sync(directory)
+        .map(|metadata| metadata.map(|m| CacheEntry::from_metadata(directory, m)))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by(|a, b| a.url.cmp(&b.url));
+    Ok(entries)
+}
+
+/// Lists every entry in a cache directory, grouped by version and ordered by
+/// endpoint.
+///
+/// ```no_run
+/// let grouped = ddragon::cache::entries_by_version("./cache").unwrap();
+/// for (version, entries) in grouped {
+///     println!("{}: {} entries", version.as_deref().unwrap_or("unversioned"), entries.len());
+/// }
+/// ```
+pub fn entries_by_version(
+    directory: &str,
+) -> Result<BTreeMap<Option<String>, Vec<CacheEntry>>, ClientError> {
+    let mut grouped: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for entry in entries(directory)? {
+        grouped.entry(entry.version.clone()).or_default().push(entry);
+    }
+    for entries in grouped.values_mut() {
+        entries.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+    }
+    Ok(grouped)
+}
+
+/// Returns the total size of every stored response in bytes. Responses that
+/// are stored under several URLs are only counted once.
+pub fn total_size(directory: &str) -> Result<u64, ClientError> {
+    let mut seen = HashSet::new();
+    Ok(entries(directory)?
+        .into_iter()
+        .filter(|e| seen.insert(e.integrity.clone()))
+        .map(|e| e.size as u64)
+        .sum())
+}
+
+/// Removes entries from a cache directory, along with any stored response no
+/// remaining entry refers to. Returns how many bytes were deleted.
+pub fn remove(directory: &str, entries_to_remove: &[CacheEntry]) -> Result<u64, ClientError> {
+    for entry in entries_to_remove {
+        cacache::remove_sync(directory, &entry.key)?;
+    }
+
+    let referenced: HashSet<_> = entries(directory)?.into_iter().map(|e| e.integrity).collect();
+    let mut removed = HashSet::new();
+    let mut freed = 0;
+    for entry in entries_to_remove {
+        if referenced.contains(&entry.integrity) || !removed.insert(&entry.integrity) {
+            continue;
+        }
+        // Content that is already missing has nothing left to free.
+        if cacache::remove_hash_sync(directory, &entry.integrity).is_ok() {
+            freed += entry.size as u64;
+        }
+    }
+    Ok(freed)
+}
+
+/// Removes every entry that doesn't belong to one of the `keep` most recent
+/// patches in the cache. Unversioned entries, like the version list, are
+/// always kept.
+///
+/// ```no_run
+/// let report = ddragon::cache::prune("./cache", 2).unwrap();
+/// println!("Removed {} entries, freeing {} bytes", report.removed.len(), report.freed);
+/// ```
+pub fn prune(directory: &str, keep: usize) -> Result<PruneReport, ClientError> {
+    let entries = entries(directory)?;
+    let patches: BTreeSet<_> = entries.iter().filter_map(|e| e.patch()).collect();
+    let kept: HashSet<_> = patches.into_iter().rev().take(keep).collect();
+
+    let removed: Vec<_> =
+        entries.into_iter().filter(|e| e.patch().is_some_and(|p| !kept.contains(&p))).collect();
+    let freed = remove(directory, &removed)?;
+    Ok(PruneReport { removed, freed })
+}
+
+/// Checks the stored response of every entry against its recorded hash, and
+/// returns the entries whose response is missing or corrupted. These can be
+/// passed to [remove] so they are fetched again.
+///
+/// ```no_run
+/// let broken = ddragon::cache::verify("./cache").unwrap();
+/// ddragon::cache::remove("./cache", &broken).unwrap();
+/// ```
+pub fn verify(directory: &str) -> Result<Vec<CacheEntry>, ClientError> {
+    Ok(entries(directory)?
+        .into_iter()
+        .filter(|e| cacache::read_hash_sync(directory, &e.integrity).is_err())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env::temp_dir, fs::remove_dir_all};
+
+    const DDRAGON: &str = "https://ddragon.leagueoflegends.com";
+
+    fn create_cache(name: &str) -> String {
+        let directory = temp_dir().join(name);
+        let _ = remove_dir_all(&directory);
+        let directory = directory.to_string_lossy().into_owned();
+
+        let writes: [(String, &[u8]); 6] = [
+            (format!("{DDRAGON}/api/versions.json"), b"[\"14.3.1\"]"),
+            (format!("{DDRAGON}/cdn/14.3.1/data/en_US/item.json"), b"new items"),
+            (format!("GET:{DDRAGON}/cdn/14.3.1/img/item/1001.png"), b"boots"),
+            (format!("{DDRAGON}/cdn/14.2.1/data/en_US/item.json"), b"old items"),
+            (format!("{DDRAGON}/cdn/14.2.1/img/item/1001.png"), b"boots"),
+            ("https://raw.communitydragon.org/14.1/cdragon/arena/en_us.json".to_owned(), b"{}"),
+        ];
+        for (key, data) in writes {
+            cacache::write_sync(&directory, key, data).unwrap();
+        }
+        directory
+    }
+
+    #[test]
+    fn splits_versions_from_paths() {
+        assert_eq!(
+            split_version("/cdn/14.3.1/img/item/1001.png"),
+            (Some("14.3.1"), "img/item/1001.png")
+        );
+        assert_eq!(
+            split_version("/14.3/cdragon/arena/en_us.json"),
+            (Some("14.3"), "cdragon/arena/en_us.json")
+        );
+        assert_eq!(split_version("/cdn/languages.json"), (None, "cdn/languages.json"));
+        assert_eq!(split_version("/cdn/img/champion/splash/Ahri_0.jpg").0, None);
+    }
+
+    #[test]
+    fn lists_entries_by_version() {
+        let directory = create_cache("cache01");
+        let grouped = entries_by_version(&directory).unwrap();
+
+        let versions: Vec<_> = grouped.keys().map(|v| v.as_deref()).collect();
+        assert_eq!(versions, vec![None, Some("14.1"), Some("14.2.1"), Some("14.3.1")]);
+
+        let endpoints: Vec<_> =
+            grouped[&Some("14.3.1".to_owned())].iter().map(|e| e.endpoint.as_str()).collect();
+        assert_eq!(endpoints, vec!["data/en_US/item.json", "img/item/1001.png"]);
+        assert_eq!(
+            grouped[&Some("14.3.1".to_owned())][1].url,
+            format!("{DDRAGON}/cdn/14.3.1/img/item/1001.png")
+        );
+    }
+
+    #[test]
+    fn total_size_counts_shared_content_once() {
+        let directory = create_cache("cache02");
+        assert_eq!(total_size(&directory).unwrap(), 10 + 9 + 5 + 9 + 2);
+    }
+
+    #[test]
+    fn sizes_unrecorded_entries_from_their_content() {
+        let directory = create_cache("cache06");
+        let integrity = cacache::write_hash_sync(&directory, b"unsized").unwrap();
+        let url = format!("{DDRAGON}/cdn/14.3.1/data/en_US/map.json");
+        cacache::index::insert(
+            directory.as_ref(),
+            &url,
+            cacache::WriteOpts::new().integrity(integrity),
+        )
+        .unwrap();
+
+        let entry = entries(&directory).unwrap().into_iter().find(|e| e.url == url).unwrap();
+        assert_eq!(entry.size, 7);
+    }
+
+    #[test]
+    fn missing_directories_are_empty() {
+        let directory = temp_dir().join("cache03");
+        let _ = remove_dir_all(&directory);
+        assert!(entries(&directory.to_string_lossy()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn prune_keeps_most_recent_patches() {
+        let directory = create_cache("cache04");
+        let report = prune(&directory, 1).unwrap();
+
+        let removed: Vec<_> = report.removed.iter().map(|e| e.version.as_deref()).collect();
+        assert_eq!(removed, vec![Some("14.2.1"), Some("14.2.1"), Some("14.1")]);
+        // The boots image is still used by 14.3.1, so only the item data and
+        // the cdragon data are actually deleted.
+        assert_eq!(report.freed, 9 + 2);
+
+        let remaining: Vec<_> =
+            entries(&directory).unwrap().into_iter().map(|e| e.version).collect();
+        assert_eq!(remaining, vec![None, Some("14.3.1".to_owned()), Some("14.3.1".to_owned())]);
+        assert!(verify(&directory).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_finds_missing_content() {
+        let directory = create_cache("cache05");
+        let items = format!("{DDRAGON}/cdn/14.3.1/data/en_US/item.json");
+        let integrity = cacache::metadata_sync(&directory, &items).unwrap().unwrap().integrity;
+        cacache::remove_hash_sync(&directory, &integrity).unwrap();
+
+        let broken = verify(&directory).unwrap();
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].url, items);
+
+        remove(&directory, &broken).unwrap();
+        assert!(verify(&directory).unwrap().is_empty());
+    }
+}
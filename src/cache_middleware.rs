@@ -1,13 +1,21 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "sync")))]
 #![warn(missing_docs)]
 
-use ureq::http::{Request, Response};
+use std::io::Write;
+
+use ureq::http::{HeaderValue, Request, Response};
 use ureq::middleware::{Middleware, MiddlewareNext};
 use ureq::{Body, Error, SendBody};
 
+use crate::cache_policy::{
+    CachePolicy, CacheRule, Lookup, OFFLINE_MISS, cached_content_type, validators,
+};
+
 /// Handles caching responses locally.
 pub struct CacheMiddleware {
     directory: String,
+    policy: CachePolicy,
+    offline: bool,
 }
 
 impl CacheMiddleware {
@@ -15,40 +23,98 @@ impl CacheMiddleware {
     /// files to go in specified. Cache file structure beyond that is dictated
     /// by `cacache`.
     pub fn new(directory: &str) -> Self {
-        Self { directory: directory.to_owned() }
+        Self { directory: directory.to_owned(), policy: CachePolicy::default(), offline: false }
+    }
+
+    /// Configures which responses get cached, and for how long. Uses
+    /// [CachePolicy::default] if not specified.
+    pub fn policy(mut self, policy: CachePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Configures the middleware to never touch the network. Any cached
+    /// response is served regardless of the policy, and anything that isn't
+    /// cached gets a `504 Gateway Timeout` response.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    fn cached_response(&self, cache_key: &str, data_type: &str) -> Option<Response<Body>> {
+        let data = cacache::read_sync(&self.directory, cache_key).ok()?;
+        Response::builder()
+            .header("Content-Type", data_type)
+            .header("Content-Length", data.len())
+            .status(200)
+            .body(Body::builder().mime_type(data_type).data(data))
+            .ok()
     }
 }
 
 impl Middleware for CacheMiddleware {
     fn handle(
         &self,
-        request: Request<SendBody>,
+        mut request: Request<SendBody>,
         next: MiddlewareNext,
     ) -> Result<Response<Body>, Error> {
-        // We always want an up-to-date version list.
-        if request.uri().path().ends_with("/api/versions.json") {
+        let data_type = cached_content_type(request.uri().path());
+        let cache_key = request.uri().to_string();
+        if self.offline {
+            return match self.cached_response(&cache_key, data_type) {
+                Some(response) => Ok(response),
+                None => Ok(Response::builder()
+                    .status(504)
+                    .header(OFFLINE_MISS, "1")
+                    .body(Body::builder().data(vec![]))?),
+            };
+        }
+
+        let rule = self.policy.rule_for(request.uri().path());
+        if rule == CacheRule::NoStore {
             return next.handle(request);
         }
 
-        let is_image = request.uri().path().ends_with(".png");
-        let cache_key = request.uri().to_string();
-        if let Ok(data) = cacache::read_sync(&self.directory, &cache_key) {
-            let data_type = if is_image { "image/png" } else { "application/json" };
-            return Ok(Response::builder()
-                .header("Content-Type", data_type)
-                .header("Content-Length", data.len())
-                .status(200)
-                .body(Body::builder().mime_type(data_type).data(data))?);
+        let entry = cacache::metadata_sync(&self.directory, &cache_key).ok().flatten();
+        match rule.lookup(entry.as_ref()) {
+            Lookup::Hit => {
+                if let Some(response) = self.cached_response(&cache_key, data_type) {
+                    return Ok(response);
+                }
+            }
+            Lookup::Revalidate(headers) => {
+                for (header, value) in headers {
+                    if let Ok(value) = HeaderValue::from_str(value) {
+                        request.headers_mut().insert(header, value);
+                    }
+                }
+            }
+            Lookup::Miss => {}
         }
 
         let mut response = next.handle(request)?;
+        if response.status() == 304 && entry.is_some() {
+            if let Some(response) = self.cached_response(&cache_key, data_type) {
+                return Ok(response);
+            }
+        }
         if response.status() != 200 {
             return Ok(response);
         }
 
+        let metadata = validators(|name| response.headers().get(name)?.to_str().ok());
+
         let body_mut = response.body_mut();
         if let Ok(body) = body_mut.read_to_vec() {
-            let _ = cacache::write_sync(&self.directory, cache_key, body.clone());
+            let writer = cacache::WriteOpts::new()
+                .metadata(metadata)
+                .size(body.len())
+                .open_sync(&self.directory, &cache_key);
+            if let Ok(mut writer) = writer {
+                if writer.write_all(&body).is_ok() {
+                    let _ = writer.commit();
+                }
+            }
             let mut body_builder = Body::builder();
             let mut reponse_builder = Response::builder();
             if let Some(mime_type) = body_mut.mime_type() {
@@ -69,12 +135,16 @@ impl Middleware for CacheMiddleware {
 mod tests {
     use super::*;
     use mockito::Server;
-    use std::{env::temp_dir, fs::remove_dir_all, path::Path};
+    use std::{env::temp_dir, fs::remove_dir_all, path::Path, time::Duration};
     use ureq::Agent;
 
     fn build_agent(cache_dir: &Path) -> Agent {
+        build_agent_with_policy(cache_dir, CachePolicy::default())
+    }
+
+    fn build_agent_with_policy(cache_dir: &Path, policy: CachePolicy) -> Agent {
         Agent::config_builder()
-            .middleware(CacheMiddleware::new(&cache_dir.to_string_lossy()))
+            .middleware(CacheMiddleware::new(&cache_dir.to_string_lossy()).policy(policy))
             .build()
             .into()
     }
@@ -127,4 +197,114 @@ mod tests {
         assert_eq!(response.status(), 200);
         assert_eq!(response.into_body().read_to_string().unwrap(), "some example text");
     }
+
+    #[test]
+    fn language_list_is_always_fetched_again() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/cdn/languages.json")
+            .with_status(200)
+            .with_body(r#"["en_US"]"#)
+            .expect(2)
+            .create();
+
+        let full_url = format!("{}/cdn/languages.json", server.url());
+
+        let cache_dir = temp_dir().join("test03");
+        let _ = remove_dir_all(&cache_dir);
+
+        let agent = build_agent(&cache_dir);
+
+        let _ = agent.get(&full_url).call().unwrap();
+        let _ = agent.get(&full_url).call().unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn expired_entries_are_fetched_again() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/file.txt")
+            .with_status(200)
+            .with_body("some example text")
+            .expect(2)
+            .create();
+
+        let full_url = format!("{}/file.txt", server.url());
+
+        let cache_dir = temp_dir().join("test04");
+        let _ = remove_dir_all(&cache_dir);
+
+        let policy = CachePolicy::new(CacheRule::Ttl(Duration::ZERO));
+        let agent = build_agent_with_policy(&cache_dir, policy);
+
+        let _ = agent.get(&full_url).call().unwrap();
+        let _ = agent.get(&full_url).call().unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn revalidated_entries_are_served_on_not_modified() {
+        let mut server = Server::new();
+        let full_url = format!("{}/file.txt", server.url());
+
+        let cache_dir = temp_dir().join("test05");
+        let _ = remove_dir_all(&cache_dir);
+
+        let agent = build_agent_with_policy(&cache_dir, CachePolicy::new(CacheRule::Revalidate));
+
+        let first = server
+            .mock("GET", "/file.txt")
+            .match_header("If-None-Match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("ETag", "\"v1\"")
+            .with_body("some example text")
+            .create();
+        let revalidation = server
+            .mock("GET", "/file.txt")
+            .match_header("If-None-Match", "\"v1\"")
+            .with_status(304)
+            .create();
+
+        let _ = agent.get(&full_url).call().unwrap();
+        let response = agent.get(&full_url).call().unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.into_body().read_to_string().unwrap(), "some example text");
+
+        first.assert();
+        revalidation.assert();
+    }
+
+    #[test]
+    fn offline_serves_cached_entries_only() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/cdn/languages.json")
+            .with_status(200)
+            .with_body(r#"["en_US"]"#)
+            .expect(1)
+            .create();
+
+        let full_url = format!("{}/cdn/languages.json", server.url());
+
+        let cache_dir = temp_dir().join("test06");
+        let _ = remove_dir_all(&cache_dir);
+
+        let _ = build_agent(&cache_dir).get(&full_url).call().unwrap();
+
+        let offline_agent: Agent = Agent::config_builder()
+            .middleware(CacheMiddleware::new(&cache_dir.to_string_lossy()).offline(true))
+            .build()
+            .into();
+
+        let response = offline_agent.get(&full_url).call().unwrap();
+        assert_eq!(response.into_body().read_to_string().unwrap(), r#"["en_US"]"#);
+
+        let uncached = offline_agent.get(&format!("{}/file.txt", server.url())).call().unwrap();
+        assert_eq!(uncached.status(), 504);
+
+        mock.assert();
+    }
 }
@@ -0,0 +1,236 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Describes how a cached response may be reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheRule {
+    /// Responses are never cached, and always requested from the network.
+    NoStore,
+    /// Responses are cached forever once fetched. This suits versioned ddragon
+    /// paths like `/cdn/14.3.1/...`, which never change once published.
+    Immutable,
+    /// Responses are cached, but fetched again once they are older than the
+    /// given duration.
+    Ttl(Duration),
+    /// Responses are cached, but checked with the server before being reused,
+    /// using the `ETag` and `Last-Modified` headers of the cached response.
+    Revalidate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Freshness {
+    Fresh,
+    Stale,
+    Revalidate,
+}
+
+/// What a cache middleware should do with a request, given the cache entry
+/// for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Lookup<'a> {
+    /// Serve the cached response.
+    Hit,
+    /// Send the request with these conditional headers, and serve the cached
+    /// response if the server answers `304 Not Modified`.
+    Revalidate(Vec<(&'static str, &'a str)>),
+    /// Send the request as-is.
+    Miss,
+}
+
+/// The validators stored with a cached response, as the metadata key, the
+/// response header it's read from, and the request header it's sent back in.
+const VALIDATORS: [(&str, &str, &str); 2] =
+    [("etag", "ETag", "If-None-Match"), ("last_modified", "Last-Modified", "If-Modified-Since")];
+
+impl CacheRule {
+    /// Decides whether an entry written at `written_at` (in unix milliseconds,
+    /// as recorded by `cacache`) can be reused as-is.
+    pub(crate) fn freshness(&self, written_at: u128) -> Freshness {
+        match self {
+            CacheRule::NoStore => Freshness::Stale,
+            CacheRule::Immutable => Freshness::Fresh,
+            CacheRule::Revalidate => Freshness::Revalidate,
+            CacheRule::Ttl(ttl) => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+                let age = now.as_millis().saturating_sub(written_at);
+                if age < ttl.as_millis() { Freshness::Fresh } else { Freshness::Stale }
+            }
+        }
+    }
+
+    /// Decides how to handle a request, given the cache entry for it.
+    pub(crate) fn lookup<'a>(&self, entry: Option<&'a cacache::Metadata>) -> Lookup<'a> {
+        let Some(entry) = entry else {
+            return Lookup::Miss;
+        };
+        match self.freshness(entry.time) {
+            Freshness::Fresh => Lookup::Hit,
+            Freshness::Stale => Lookup::Miss,
+            Freshness::Revalidate => Lookup::Revalidate(
+                VALIDATORS
+                    .iter()
+                    .filter_map(|(key, _, header)| {
+                        Some((*header, entry.metadata.get(key)?.as_str()?))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// The metadata to store with a response, read from its headers with
+/// `header`, so the response can be revalidated later.
+pub(crate) fn validators<'a>(header: impl Fn(&str) -> Option<&'a str>) -> cacache::Value {
+    VALIDATORS
+        .iter()
+        .filter_map(|(key, name, _)| Some((key.to_string(), cacache::Value::from(header(name)?))))
+        .collect()
+}
+
+/// The header a cache middleware marks its `504 Gateway Timeout` with when it
+/// can't serve a request offline, so it isn't mistaken for a real one.
+pub(crate) const OFFLINE_MISS: &str = "x-ddragon-offline-miss";
+
+/// The content type a cached response for `path` is served with.
+pub(crate) fn cached_content_type(path: &str) -> &'static str {
+    if path.ends_with(".png") { "image/png" } else { "application/json" }
+}
+
+/// Maps URL paths to the [CacheRule] that applies to them.
+///
+/// Patterns are matched against the path of the requested URL, where `*`
+/// matches any run of characters (including `/`). Rules are checked in the
+/// order they were added, and the first match wins. Paths that match no rule
+/// use the fallback rule.
+///
+/// ```
+/// use std::time::Duration;
+/// use ddragon::cache_policy::{CachePolicy, CacheRule};
+///
+/// let hourly = CacheRule::Ttl(Duration::from_secs(60 * 60));
+/// let policy = CachePolicy::new(CacheRule::Immutable)
+///     .rule("/api/versions.json", CacheRule::NoStore)
+///     .rule("/*/cdragon/*", hourly);
+///
+/// assert_eq!(policy.rule_for("/14.3/cdragon/arena/en_us.json"), hourly);
+/// assert_eq!(policy.rule_for("/cdn/14.3.1/data/en_US/item.json"), CacheRule::Immutable);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+    rules: Vec<(String, CacheRule)>,
+    fallback: CacheRule,
+}
+
+impl CachePolicy {
+    /// Creates a policy with no rules, where every path uses `fallback`.
+    pub fn new(fallback: CacheRule) -> Self {
+        Self { rules: vec![], fallback }
+    }
+
+    /// Adds a rule for every path matching `pattern`.
+    pub fn rule(mut self, pattern: &str, rule: CacheRule) -> Self {
+        self.rules.push((pattern.to_owned(), rule));
+        self
+    }
+
+    /// Returns the rule that applies to a URL path.
+    pub fn rule_for(&self, path: &str) -> CacheRule {
+        self.rules
+            .iter()
+            .find(|(pattern, _)| matches_pattern(pattern, path))
+            .map(|(_, rule)| *rule)
+            .unwrap_or(self.fallback)
+    }
+}
+
+/// The default policy:
+///
+/// - The version and language lists are always fetched again, but the most
+///   recent copy is kept around for offline use.
+/// - cdragon data is revalidated, as it can change within a patch.
+/// - Everything else is immutable.
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::new(CacheRule::Immutable)
+            .rule("/api/versions.json", CacheRule::Ttl(Duration::ZERO))
+            .rule("/cdn/languages.json", CacheRule::Ttl(Duration::ZERO))
+            .rule("/*/cdragon/*", CacheRule::Revalidate)
+    }
+}
+
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == path,
+        Some((prefix, rest)) => {
+            let Some(remaining) = path.strip_prefix(prefix) else {
+                return false;
+            };
+            remaining
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([remaining.len()])
+                .any(|i| matches_pattern(rest, &remaining[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_patterns_match_whole_path() {
+        assert!(matches_pattern("/api/versions.json", "/api/versions.json"));
+        assert!(!matches_pattern("/api/versions.json", "/api/versions.json.bak"));
+    }
+
+    #[test]
+    fn wildcards_match_across_segments() {
+        assert!(matches_pattern("/*/cdragon/*", "/14.3/cdragon/arena/en_us.json"));
+        assert!(matches_pattern("*.png", "/cdn/14.3.1/img/champion/Ahri.png"));
+        assert!(!matches_pattern("/*/cdragon/*", "/cdn/14.3.1/data/en_US/item.json"));
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let policy = CachePolicy::new(CacheRule::Immutable)
+            .rule("/cdn/*.png", CacheRule::NoStore)
+            .rule("/cdn/*", CacheRule::Revalidate);
+
+        assert_eq!(policy.rule_for("/cdn/14.3.1/img/item/1001.png"), CacheRule::NoStore);
+        assert_eq!(policy.rule_for("/cdn/14.3.1/data/en_US/item.json"), CacheRule::Revalidate);
+        assert_eq!(policy.rule_for("/other"), CacheRule::Immutable);
+    }
+
+    #[test]
+    fn ttl_expires_old_entries() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+        let rule = CacheRule::Ttl(Duration::from_secs(60));
+
+        assert_eq!(rule.freshness(now), Freshness::Fresh);
+        assert_eq!(rule.freshness(now - 120_000), Freshness::Stale);
+    }
+
+    #[test]
+    fn revalidation_sends_stored_validators() {
+        let cache_dir = std::env::temp_dir().join("policy01");
+        let _ = std::fs::remove_dir_all(&cache_dir);
+        let directory = cache_dir.to_string_lossy();
+
+        let metadata = validators(|name| (name == "ETag").then_some("\"v1\""));
+        let opts = cacache::WriteOpts::new().metadata(metadata);
+        let mut writer = opts.open_sync(&*directory, "key").unwrap();
+        std::io::Write::write_all(&mut writer, b"data").unwrap();
+        writer.commit().unwrap();
+        let entry = cacache::metadata_sync(&*directory, "key").unwrap();
+
+        assert_eq!(
+            CacheRule::Revalidate.lookup(entry.as_ref()),
+            Lookup::Revalidate(vec![("If-None-Match", "\"v1\"")])
+        );
+        assert_eq!(CacheRule::Immutable.lookup(entry.as_ref()), Lookup::Hit);
+        assert_eq!(CacheRule::Revalidate.lookup(None), Lookup::Miss);
+    }
+}
@@ -7,26 +7,31 @@ use mockito;
 #[cfg(feature = "image")]
 use image::{DynamicImage, load_from_memory};
 
-#[cfg(feature = "image")]
-use std::io::Read;
-
 use serde::de::DeserializeOwned;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
 use ureq::Agent;
 use url::Url;
 
+use crate::batch::BatchReport;
 use crate::cache_middleware::CacheMiddleware;
+use crate::cache_policy::CachePolicy;
+use crate::drift::DriftReport;
+use crate::endpoint::{self, Endpoint, data_endpoints};
+use crate::memo::Memo;
+use crate::prefetch::{
+    Listing, Listings, PrefetchPlan, PrefetchProgress, PrefetchQueue, PrefetchReport,
+};
+use crate::retry::RetryPolicy;
+use crate::source::{DataSource, HttpSource, Resource};
 
 #[cfg(feature = "image")]
 use crate::models::shared::HasImage;
-use crate::models::tft::{
-    self, Arenas, Augments, HeroAugments, Queues, Regalia, Tacticians, Traits,
-};
 use crate::{
     ClientError,
-    models::{
-        Challenges, Champion, Champions, ChampionsFull, Items, Maps, MissionAssets, ProfileIcons,
-        Runes, SpellBuffs, SummonerSpells, Translations, champion::ChampionWrapper,
-    },
+    models::{self, Champion, Locale, Versions},
 };
 
 /// Used for building a [Client] with custom options.
@@ -34,7 +39,15 @@ pub struct ClientBuilder {
     server: String,
     agent: Option<Agent>,
     cache: Option<String>,
+    cache_policy: CachePolicy,
     version: Option<String>,
+    locale: Locale,
+    offline: bool,
+    retry_policy: RetryPolicy,
+    report_drift: bool,
+    concurrency: usize,
+    memoize: Option<usize>,
+    source: Option<Arc<dyn DataSource>>,
     #[cfg(feature = "cdragon")]
     cdragon_server: String,
 }
@@ -78,7 +91,15 @@ impl ClientBuilder {
             server: "https://ddragon.leagueoflegends.com".to_owned(),
             agent: None,
             cache: None,
+            cache_policy: CachePolicy::default(),
             version: None,
+            locale: Locale::default(),
+            offline: false,
+            retry_policy: RetryPolicy::never(),
+            report_drift: false,
+            concurrency: 8,
+            memoize: None,
+            source: None,
             #[cfg(feature = "cdragon")]
             cdragon_server: "https://raw.communitydragon.org".to_owned(),
         }
@@ -96,6 +117,14 @@ impl ClientBuilder {
         self
     }
 
+    /// Configures which responses get cached, and for how long. Only applies
+    /// when a cache directory is specified. Uses [CachePolicy::default] if not
+    /// specified.
+    pub fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.cache_policy = policy;
+        self
+    }
+
     /// Configure the ddragon version for making requests. Normally this should
     /// not be needed, as the latest version is always used.
     pub fn version(mut self, version: &str) -> Self {
@@ -103,6 +132,84 @@ impl ClientBuilder {
         self
     }
 
+    /// Configure the language data is requested in. Defaults to
+    /// [Locale::EnUs].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Configures the client to only ever serve data from the cache. The most
+    /// recently fetched version list is used to pick the latest version, and
+    /// any request for data that isn't cached fails with
+    /// [ClientError::NotCached].
+    ///
+    /// This requires a cache directory or a custom source, and building fails
+    /// with [ClientError::OfflineWithoutCache] otherwise. If a custom agent is
+    /// used instead, its [CacheMiddleware] must also be configured with
+    /// [CacheMiddleware::offline].
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Configures when failed requests are retried, for both data and images.
+    /// Requests aren't retried unless this is set. Doesn't apply to a custom
+    /// [DataSource].
+    ///
+    /// ```no_run
+    /// use ddragon::{ClientBuilder, retry::RetryPolicy};
+    ///
+    /// let client = ClientBuilder::new().retry_policy(RetryPolicy::default()).build().unwrap();
+    /// ```
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Configures the client to collect every field it receives that the
+    /// models don't know about, e.g. after Riot adds a field, instead of
+    /// silently dropping them. See [Client::drift_report].
+    pub fn report_drift(mut self, report_drift: bool) -> Self {
+        self.report_drift = report_drift;
+        self
+    }
+
+    /// Sets how many requests bulk methods like [Client::champions_detailed]
+    /// make at once. Defaults to 8, and is at least 1.
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// Configures the client to keep up to `entries` parsed resources in
+    /// memory, which are returned instead of reading and deserializing them
+    /// again. The least recently used resource is dropped to make room for
+    /// another. Only versioned ddragon data that the
+    /// [cache policy](Self::cache_policy) treats as immutable is kept, so the
+    /// version list and cdragon data are still always fetched.
+    ///
+    /// This limits the number of resources, not the memory they use: the
+    /// whole of [Client::champions_full] takes up a single entry, the same as
+    /// one champion.
+    ///
+    /// [Client::fetch_shared] returns memoized data without copying it.
+    /// Methods like [Client::champions] and [Client::items] return a clone of
+    /// it, which still skips reading and deserializing the data.
+    pub fn memoize(mut self, entries: usize) -> Self {
+        self.memoize = Some(entries);
+        self
+    }
+
+    /// Configures the client to read everything from a [DataSource] instead
+    /// of the network, e.g. an extracted dragontail archive with
+    /// [DirectorySource](crate::source::DirectorySource). Any agent and
+    /// caching options are ignored.
+    pub fn source(mut self, source: impl DataSource + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
     #[cfg(test)]
     #[allow(missing_docs)]
     pub fn server(mut self, server: &str) -> Self {
@@ -120,46 +227,69 @@ impl ClientBuilder {
     /// - If a custom agent is specified, not specifying a cache directory will
     ///   result in images not being cached if you are using the `image` feature.
     pub fn build(self) -> Result<Client, ClientError> {
-        let agent = match self.agent {
-            Some(a) => a,
-            None => match self.cache.clone() {
-                Some(dir) => {
-                    Agent::config_builder().middleware(CacheMiddleware::new(&dir)).build().into()
-                }
-                None => Agent::new_with_defaults(),
-            },
+        let memo_policy = self.cache_policy.clone();
+        let base_url = Url::parse(&self.server)?;
+        #[cfg(feature = "cdragon")]
+        let cdragon_base_url = Url::parse(&self.cdragon_server)?;
+
+        // The cache directory is only known to be in use if we set up the
+        // middleware ourselves.
+        let cache = match (&self.agent, &self.source) {
+            (None, None) => self.cache,
+            _ => None,
+        };
+        // Without the middleware, nothing would stop requests reaching the
+        // network.
+        if self.offline && cache.is_none() && self.agent.is_none() && self.source.is_none() {
+            return Err(ClientError::OfflineWithoutCache);
+        }
+        let source = match self.source {
+            Some(source) => source,
+            None => {
+                let agent = match (self.agent, &cache) {
+                    (Some(a), _) => a,
+                    (None, Some(dir)) => Agent::config_builder()
+                        .middleware(
+                            CacheMiddleware::new(dir)
+                                .policy(self.cache_policy)
+                                .offline(self.offline),
+                        )
+                        .build()
+                        .into(),
+                    (None, None) => Agent::new_with_defaults(),
+                };
+                let source = HttpSource::new(agent)
+                    .offline(self.offline)
+                    .retry(self.retry_policy)
+                    .server(base_url.clone());
+                #[cfg(feature = "cdragon")]
+                let source = source.cdragon_server(cdragon_base_url.clone());
+                Arc::new(source)
+            }
+        };
+
+        let client = Client {
+            source,
+            cache,
+            version: String::new(),
+            locale: self.locale,
+            drift: self.report_drift.then(Default::default),
+            concurrency: self.concurrency,
+            memo: self.memoize.map(|entries| Arc::new(Memo::new(entries, memo_policy))),
+            base_url,
+            #[cfg(feature = "cdragon")]
+            cdragon_base_url,
         };
 
-        let base_url = Url::parse(&self.server)?;
         let latest_version = if let Some(version) = self.version {
             version
         } else {
-            let version_list = agent
-                .get(base_url.join("/api/versions.json")?.as_str())
-                .call()
-                .map_err(Box::new)?
-                .into_body()
-                .read_json::<Vec<String>>()
-                .map_err(Box::new)?;
+            let version_list = client.get_data::<Vec<String>>(Resource::Versions)?;
 
             version_list.first().ok_or(ClientError::NoLatestVersion)?.to_owned()
         };
 
-        Ok(Client {
-            agent,
-            #[cfg(feature = "cdragon")]
-            cdragon_version: latest_version
-                .clone()
-                .split('.')
-                .take(2)
-                .map(|p| p.to_owned())
-                .collect::<Vec<String>>()
-                .join("."),
-            version: latest_version,
-            base_url,
-            #[cfg(feature = "cdragon")]
-            cdragon_base_url: Url::parse(&self.cdragon_server)?,
-        })
+        Ok(client.at_version(&latest_version))
     }
 }
 
@@ -172,19 +302,23 @@ impl Default for ClientBuilder {
 #[derive(Clone)]
 /// Provides access to the ddragon API.
 pub struct Client {
-    agent: Agent,
+    source: Arc<dyn DataSource>,
+    cache: Option<String>,
     /// The current version of the API data reported back to us from the API.
     pub version: String,
+    /// The language used for any localized data.
+    pub locale: Locale,
+    drift: Option<Arc<Mutex<DriftReport>>>,
+    concurrency: usize,
+    memo: Option<Arc<Memo>>,
     base_url: Url,
 
     #[cfg(feature = "cdragon")]
     cdragon_base_url: Url,
-    #[cfg(feature = "cdragon")]
-    cdragon_version: String,
 }
 
 macro_rules! create_endpoint {
-    ($name:ident, $kind:literal, $path:literal, $ret:ty) => {
+    ($name:ident, $endpoint:ident, $kind:literal, $ret:ty) => {
         #[doc = concat!(" Returns ", $kind, " data.")]
         #[doc = ""]
         #[doc = " ```no_run"]
@@ -194,11 +328,17 @@ macro_rules! create_endpoint {
         #[doc = concat!(" let ", stringify!($name), " = api.", stringify!($name), "().unwrap();")]
         #[doc = " ```"]
         pub fn $name(&self) -> Result<$ret, ClientError> {
-            self.get_data_ddragon::<$ret>(concat!("./", $path, ".json"))
+            self.fetch_memoized(&endpoint::$endpoint)
         }
     };
 }
 
+macro_rules! create_endpoints {
+    ($(($name:ident, $endpoint:ident, $kind:literal, $path:literal, $ret:ty)),+ $(,)?) => {
+        $(create_endpoint!($name, $endpoint, $kind, $ret);)+
+    };
+}
+
 impl Client {
     /// Creates a new client with the specified directory as the caching location
     /// for any data the client downloads.
@@ -212,58 +352,190 @@ impl Client {
         ClientBuilder::new().cache(cache_dir).build()
     }
 
-    fn get_data_url(&self) -> Result<Url, url::ParseError> {
-        self.base_url.join(&format!("/cdn/{}/data/en_US/", &self.version))
+    /// Returns a copy of this client that requests localized data in a
+    /// different language. The underlying agent (and therefore the cache) is
+    /// shared with the original client.
+    ///
+    /// ```no_run
+    /// use ddragon::{Client, models::Locale};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champions = api.with_locale(Locale::KoKr).champions().unwrap();
+    /// ```
+    pub fn with_locale(&self, locale: Locale) -> Self {
+        Self { locale, ..self.clone() }
     }
 
-    #[cfg(feature = "cdragon")]
-    fn get_cdragon_data_url(&self) -> Result<Url, url::ParseError> {
-        self.cdragon_base_url.join(&format!("/{}/cdragon/", &self.cdragon_version))
+    /// Returns a copy of this client that requests data for a different
+    /// ddragon version. The underlying agent (and therefore the cache) and the
+    /// locale are shared with the original client, so this is cheap enough to
+    /// do for every historical patch you need.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let old_items = api.at_version("13.24.1").items().unwrap();
+    /// let new_items = api.items().unwrap();
+    /// ```
+    pub fn at_version(&self, version: &str) -> Self {
+        Self { version: version.to_owned(), ..self.clone() }
+    }
+
+    /// Returns every field received so far that the models don't know about,
+    /// if [ClientBuilder::report_drift] is enabled. The report is shared with any
+    /// copies of this client, e.g. from [Client::at_version].
+    ///
+    /// ```no_run
+    /// use ddragon::ClientBuilder;
+    ///
+    /// let api = ClientBuilder::new().report_drift(true).build().unwrap();
+    /// let items = api.items().unwrap();
+    /// if let Some(drift) = api.drift_report().filter(|d| !d.is_empty()) {
+    ///     println!("Unknown fields:\n{drift}");
+    /// }
+    /// ```
+    pub fn drift_report(&self) -> Option<DriftReport> {
+        self.drift.as_ref().map(|drift| drift.lock().unwrap().clone())
+    }
+
+    fn get_url(&self, resource: &Resource) -> Result<Url, url::ParseError> {
+        match resource {
+            #[cfg(feature = "cdragon")]
+            Resource::CDragon { .. } => self.cdragon_base_url.join(&resource.path()),
+            _ => self.base_url.join(&resource.path()),
+        }
     }
 
-    fn get_data<T: DeserializeOwned>(&self, request_url: &str) -> Result<T, ClientError> {
-        self.agent
-            .get(request_url)
-            .call()
-            .map_err(Box::new)?
-            .into_body()
-            .read_json::<T>()
-            .map_err(|e| Box::new(e).into())
+    #[cfg(test)]
+    fn data_resource(&self, path: &str) -> Resource {
+        endpoint::data_resource(&self.version, &self.locale, path)
     }
 
-    fn get_data_ddragon<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ClientError> {
-        let joined_url = self.get_data_url()?.join(endpoint)?;
-        let request_url = joined_url.as_str();
-        self.get_data(request_url)
+    fn get_data<T: DeserializeOwned>(&self, resource: Resource) -> Result<T, ClientError> {
+        let data = self.source.fetch(&resource)?;
+        let url = || self.get_url(&resource).map_or_else(|_| resource.path(), |u| u.to_string());
+        endpoint::deserialize(&data, url, self.drift.as_deref())
     }
 
-    #[cfg(feature = "cdragon")]
-    fn get_data_cdragon<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ClientError> {
-        let joined_url = self.get_cdragon_data_url()?.join(endpoint)?;
-        let request_url = joined_url.as_str();
-        self.get_data(request_url)
-    }
-
-    create_endpoint!(challenges, "challenge", "challenges", Challenges);
-    create_endpoint!(champions, "champion", "champion", Champions);
-    create_endpoint!(champions_full, "complete champion", "championFull", ChampionsFull);
-    create_endpoint!(items, "item", "item", Items);
-    create_endpoint!(maps, "map", "map", Maps);
-    create_endpoint!(mission_assets, "mission asset", "mission-assets", MissionAssets);
-    create_endpoint!(profile_icons, "profile icon", "profileicon", ProfileIcons);
-    create_endpoint!(runes, "rune", "runesReforged", Runes);
-    create_endpoint!(spell_buffs, "spell buff", "spellbuffs", SpellBuffs);
-    create_endpoint!(summoner_spells, "summoner_spells", "summoner", SummonerSpells);
-    create_endpoint!(translations, "translation", "language", Translations);
-    create_endpoint!(tft_arenas, "TFT arena", "tft-arena", Arenas);
-    create_endpoint!(tft_augments, "TFT augment", "tft-augments", Augments);
-    create_endpoint!(tft_champions, "TFT champion", "tft-champion", tft::Champions);
-    create_endpoint!(tft_hero_augments, "TFT hero augment", "tft-hero-augments", HeroAugments);
-    create_endpoint!(tft_items, "TFT item", "tft-item", tft::Items);
-    create_endpoint!(tft_queues, "TFT queue", "tft-queues", Queues);
-    create_endpoint!(tft_regalia, "TFT regalia", "tft-regalia", Regalia);
-    create_endpoint!(tft_tacticians, "TFT tactician", "tft-tactician", Tacticians);
-    create_endpoint!(tft_traits, "TFT trait", "tft-trait", Traits);
+    /// Returns the data for any [Endpoint], using this client's version,
+    /// locale and cache. Useful for data files this crate doesn't have a
+    /// method for yet, through [DataEndpoint](endpoint::DataEndpoint).
+    ///
+    /// ```no_run
+    /// use ddragon::{Client, endpoint::{DataEndpoint, Items}, models};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let items = api.fetch(&Items).unwrap();
+    /// let maps = api.fetch(&DataEndpoint::<models::Maps>::new("map.json")).unwrap();
+    /// ```
+    pub fn fetch<E: Endpoint>(&self, endpoint: &E) -> Result<E::Output, ClientError> {
+        endpoint.output(self.get_data(endpoint.resource(&self.version, &self.locale))?)
+    }
+
+    /// Returns the data for any [Endpoint] behind an [Arc], which is shared
+    /// with later calls for the same data if [ClientBuilder::memoize] is
+    /// enabled. This skips reading and deserializing data that was already
+    /// parsed, so suits hot paths.
+    ///
+    /// ```no_run
+    /// use ddragon::{ClientBuilder, endpoint::Champions};
+    ///
+    /// let api = ClientBuilder::new().memoize(64).build().unwrap();
+    /// let champions = api.fetch_shared(&Champions).unwrap();
+    /// let again = api.fetch_shared(&Champions).unwrap();
+    /// assert!(std::sync::Arc::ptr_eq(&champions, &again));
+    /// ```
+    pub fn fetch_shared<E>(&self, endpoint: &E) -> Result<Arc<E::Output>, ClientError>
+    where
+        E: Endpoint + 'static,
+        E::Output: Send + Sync + 'static,
+    {
+        let resource = endpoint.resource(&self.version, &self.locale);
+        let Some(memo) = &self.memo else {
+            return Ok(Arc::new(endpoint.output(self.get_data(resource)?)?));
+        };
+        if let Some(output) = memo.get::<E, _>(&resource) {
+            return Ok(output);
+        }
+
+        let output = Arc::new(endpoint.output(self.get_data(resource.clone())?)?);
+        memo.insert::<E, _>(resource, output.clone());
+        Ok(output)
+    }
+
+    /// Returns the data for an [Endpoint], cloned from the memo if
+    /// [ClientBuilder::memoize] is enabled. The typed methods like
+    /// [Client::champions] use this, so they still skip deserializing data
+    /// that was already parsed.
+    fn fetch_memoized<E>(&self, endpoint: &E) -> Result<E::Output, ClientError>
+    where
+        E: Endpoint + 'static,
+        E::Output: Clone + Send + Sync + 'static,
+    {
+        match &self.memo {
+            Some(_) => Ok(E::Output::clone(&*self.fetch_shared(endpoint)?)),
+            None => self.fetch(endpoint),
+        }
+    }
+
+    /// Returns the raw bytes behind any [Endpoint], without deserializing
+    /// them.
+    ///
+    /// ```no_run
+    /// use ddragon::{Client, endpoint::DataEndpoint};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let raw = api.fetch_raw(&DataEndpoint::<()>::new("feats.json")).unwrap();
+    /// println!("{}", String::from_utf8_lossy(&raw));
+    /// ```
+    pub fn fetch_raw<E: Endpoint>(&self, endpoint: &E) -> Result<Vec<u8>, ClientError> {
+        self.source.fetch(&endpoint.resource(&self.version, &self.locale))
+    }
+
+    data_endpoints!(create_endpoints);
+
+    /// Returns every version ddragon has published, newest first.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let versions = api.versions().unwrap();
+    /// let latest_14_3 = versions.latest_for(14, 3);
+    /// ```
+    pub fn versions(&self) -> Result<Versions, ClientError> {
+        self.fetch(&endpoint::Versions)
+    }
+
+    /// Returns a copy of this client pinned to the ddragon version matching a
+    /// game version, as reported in match data. The newest ddragon version for
+    /// the same patch is used, e.x. `14.3.558.2130` resolves to `14.3.1`.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let api_14_3 = api.version_for_game("14.3.558.2130").unwrap();
+    /// ```
+    pub fn version_for_game(&self, game_version: &str) -> Result<Self, ClientError> {
+        let versions = self.versions()?;
+        let version =
+            versions.for_game_version(game_version).ok_or(ClientError::NoMatchingVersion)?;
+        Ok(self.at_version(&version.to_string()))
+    }
+
+    /// Returns the list of languages ddragon publishes data in.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let languages = api.languages().unwrap();
+    /// ```
+    pub fn languages(&self) -> Result<Vec<Locale>, ClientError> {
+        self.fetch(&endpoint::Languages)
+    }
 
     /// Returns data for a single champion. The champion's name or numeric key
     /// should not be used here -- this should be the key property on the
@@ -277,37 +549,135 @@ impl Client {
     /// let wukong = api.champion("MonkeyKing").unwrap();
     /// ```
     pub fn champion(&self, key: &str) -> Result<Champion, ClientError> {
-        self.get_data_ddragon::<ChampionWrapper>(&format!("./champion/{key}.json"))?
-            .data
-            .get(key)
-            .cloned()
-            .ok_or(ClientError::NoChampionData)
+        self.fetch_memoized(&endpoint::Champion::new(key))
+    }
+
+    /// Returns data for many champions at once, making up to
+    /// [ClientBuilder::concurrency] requests at a time. Champions that fail
+    /// don't stop the rest, and are listed in the returned report instead.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let report = api.champions_detailed(&["Ahri", "MonkeyKing"]);
+    /// let ahri = &report.fetched["Ahri"];
+    /// ```
+    pub fn champions_detailed(&self, keys: &[&str]) -> BatchReport<Champion> {
+        let next = AtomicUsize::new(0);
+        let workers = self.concurrency.min(keys.len());
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        while let Some(key) = keys.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            results.push((key.to_string(), self.champion(key)));
+                        }
+                        results
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+
+    /// Returns data for every champion, as with [Client::champions_detailed].
+    /// Only fails if the list of champions can't be fetched.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let report = api.all_champions_detailed().unwrap();
+    /// assert!(report.is_complete());
+    /// ```
+    pub fn all_champions_detailed(&self) -> Result<BatchReport<Champion>, ClientError> {
+        let champions = self.champions()?;
+        let keys: Vec<&str> = champions.data.keys().map(String::as_str).collect();
+        Ok(self.champions_detailed(&keys))
+    }
+
+    /// Downloads everything covered by a [PrefetchPlan] for this client's
+    /// version and locale, so it can be served from the cache later (e.g. by
+    /// an offline client). Anything already cached is skipped, and
+    /// `on_progress` is called after each resource is handled.
+    ///
+    /// Failed downloads don't stop the prefetch, and are listed in the
+    /// returned report instead. This includes the data listing champions,
+    /// items and summoner spells, when it is needed to find their images.
+    ///
+    /// Without a cache directory, e.g. with a custom agent, downloads are
+    /// reported as [uncached](crate::prefetch::PrefetchStatus::Uncached), as
+    /// the client can't tell whether they were stored anywhere.
+    ///
+    /// ```no_run
+    /// use ddragon::{Client, prefetch::PrefetchPlan};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let report = api
+    ///     .prefetch(PrefetchPlan::everything(), |progress| {
+    ///         println!("{}/{} {}", progress.completed, progress.total, progress.url);
+    ///     })
+    ///     .unwrap();
+    /// assert!(report.is_complete());
+    /// ```
+    pub fn prefetch(
+        &self,
+        plan: PrefetchPlan,
+        mut on_progress: impl FnMut(PrefetchProgress<'_>),
+    ) -> Result<PrefetchReport, ClientError> {
+        // Listings are downloaded up front to find what else to fetch.
+        let mut listings = Listings::default();
+        if plan.needs_champions() {
+            listings.champions = Some(self.prefetch_listing(&endpoint::ChampionsFull)?);
+        }
+        if plan.needs_images() {
+            listings.items = Some(self.prefetch_listing(&endpoint::Items)?);
+            listings.summoner_spells = Some(self.prefetch_listing(&endpoint::SummonerSpells)?);
+        }
+
+        let stored = self.cache.is_some();
+        let mut queue =
+            PrefetchQueue::new(plan, &self.version, &self.locale, listings, stored, |resource| {
+                self.get_url(resource)
+            })?;
+        for (url, resource, listed) in queue.take_pending() {
+            let result = match listed {
+                Some(fetched) => Ok(fetched),
+                None if self.is_cached(&url) => Ok(false),
+                None => self.source.fetch(&resource).map(|_| true),
+            };
+            queue.record(url, result, &mut on_progress);
+        }
+
+        Ok(queue.into_report())
+    }
+
+    fn prefetch_listing<E: Endpoint>(
+        &self,
+        endpoint: &E,
+    ) -> Result<Listing<E::Output>, ClientError> {
+        let resource = endpoint.resource(&self.version, &self.locale);
+        let cached = self.is_cached(self.get_url(&resource)?.as_str());
+        let data = self.fetch(endpoint).map(|data| (data, !cached));
+        Ok(Listing { resource, data })
+    }
+
+    fn is_cached(&self, request_url: &str) -> bool {
+        self.cache
+            .as_ref()
+            .is_some_and(|dir| cacache::metadata_sync(dir, request_url).ok().flatten().is_some())
     }
 
     #[cfg(feature = "image")]
-    fn get_image(&self, path: Url) -> Result<DynamicImage, ClientError> {
-        let response = self
-            .agent
-            .get(path.as_str())
-            .call()
-            .map_err(|e| std::convert::Into::<ClientError>::into(Box::new(e)))?;
-
-        // We don't want to assume we can just read_to_end cleanly, so ideally
-        // we get a header telling us how many bytes we can read. If we can't,
-        // using 1 as a default means parsing will always fail and produce an
-        // error that can be handled elsewhere.
-        let image_size_bytes = response
-            .headers()
-            .get("Content-Length")
-            .map(|s| s.to_str().unwrap_or("1"))
-            .unwrap_or("1")
-            .parse::<u64>()
-            .unwrap_or(1);
-
-        let mut image_buffer: Vec<u8> = vec![];
-        response.into_body().into_reader().take(image_size_bytes).read_to_end(&mut image_buffer)?;
-
-        load_from_memory(&image_buffer).map_err(|e| e.into())
+    fn get_image(&self, resource: Resource) -> Result<DynamicImage, ClientError> {
+        load_from_memory(&self.source.fetch(&resource)?).map_err(|e| e.into())
     }
 
     /// Returns an [image::DynamicImage].
@@ -322,11 +692,7 @@ impl Client {
     #[cfg(feature = "image")]
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub fn image_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.image_path()
-        ))?)
+        self.get_image(Resource::Image { version: self.version.clone(), path: item.image_path() })
     }
 
     /// Returns an [image::DynamicImage].
@@ -344,11 +710,7 @@ impl Client {
     #[cfg(feature = "image")]
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub fn sprite_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.sprite_path()
-        ))?)
+        self.get_image(Resource::Image { version: self.version.clone(), path: item.sprite_path() })
     }
 
     /// [cdragon] Returns augment data for the Arena game mode.
@@ -361,18 +723,24 @@ impl Client {
     /// ```
     #[cfg(feature = "cdragon")]
     #[cfg_attr(docsrs, doc(cfg(feature = "cdragon")))]
-    pub fn arena_augments(&self) -> Result<Vec<crate::models::Augment>, ClientError> {
-        self.get_data_cdragon::<crate::models::cdragon::augments::AugmentsResponse>(
-            "./arena/en_us.json",
-        )
-        .map(|r| r.augments)
+    pub fn arena_augments(&self) -> Result<Vec<models::Augment>, ClientError> {
+        self.fetch_memoized(&endpoint::ArenaAugments)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use mockito::{Server, ServerGuard};
+    use crate::endpoint::DATA_ENDPOINTS;
+    use crate::fixtures::champion_json;
+    use crate::source::DirectorySource;
+    use mockito::{Matcher, Server, ServerGuard};
+    use std::{
+        collections::HashMap,
+        env::temp_dir,
+        fs::{create_dir_all, remove_dir_all, write},
+        time::Duration,
+    };
 
     fn create_mock_client() -> (ServerGuard, String, Client) {
         let server = Server::new();
@@ -382,12 +750,17 @@ mod test {
             server,
             url.clone(),
             Client {
-                agent: Agent::new_with_defaults(),
+                source: Arc::new(
+                    HttpSource::new(Agent::new_with_defaults()).server(parsed.clone()),
+                ),
+                cache: None,
                 version: "0.0.0".to_owned(),
+                locale: Locale::EnUs,
+                drift: None,
+                concurrency: 8,
+                memo: None,
                 #[cfg(feature = "cdragon")]
                 cdragon_base_url: parsed.clone(),
-                #[cfg(feature = "cdragon")]
-                cdragon_version: "0.0".to_owned(),
                 base_url: parsed,
             },
         )
@@ -473,24 +846,128 @@ mod test {
             assert!(maybe_client.is_ok());
             assert_eq!(maybe_client.unwrap().version, "3.3.3");
         }
+
+        #[test]
+        fn result_ok_offline_from_cached_versions() {
+            let mut server = Server::new();
+            let mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["1.1.1", "0.0.0"]"#)
+                .expect(1)
+                .create();
+
+            let cache_dir = temp_dir().join("client-offline01");
+            let _ = remove_dir_all(&cache_dir);
+            let cache_dir = cache_dir.to_string_lossy();
+
+            let _ = ClientBuilder::new().server(&server.url()).cache(&cache_dir).build().unwrap();
+            let offline_client =
+                ClientBuilder::new().server(&server.url()).cache(&cache_dir).offline(true).build();
+
+            assert_eq!(offline_client.unwrap().version, "1.1.1");
+            mock.assert();
+        }
+
+        #[test]
+        fn result_err_offline_without_cached_versions() {
+            let cache_dir = temp_dir().join("client-offline02");
+            let _ = remove_dir_all(&cache_dir);
+
+            let maybe_client = ClientBuilder::new()
+                .server("https://a-very-fake.urltogoto")
+                .cache(&cache_dir.to_string_lossy())
+                .offline(true)
+                .build();
+
+            assert!(matches!(maybe_client, Err(ClientError::NotCached { .. })));
+        }
+
+        #[test]
+        fn result_err_offline_without_cache() {
+            let maybe_client = ClientBuilder::new().offline(true).build();
+
+            assert!(matches!(maybe_client, Err(ClientError::OfflineWithoutCache)));
+        }
+
+        #[test]
+        fn result_ok_from_directory_source() {
+            let root = temp_dir().join("client-source01");
+            let _ = remove_dir_all(&root);
+            create_dir_all(root.join("14.3.1/data/en_US")).unwrap();
+            create_dir_all(root.join("14.2.1/data/en_US")).unwrap();
+            write(root.join("languages.json"), r#"["en_US", "ko_KR"]"#).unwrap();
+
+            let client = ClientBuilder::new()
+                .server("https://a-very-fake.urltogoto")
+                .source(DirectorySource::new(&root))
+                .build()
+                .unwrap();
+
+            assert_eq!(client.version, "14.3.1");
+            assert_eq!(client.languages().unwrap(), vec![Locale::EnUs, Locale::KoKr]);
+            assert!(matches!(client.items(), Err(ClientError::NotFound { .. })));
+        }
+
+        #[test]
+        fn result_ok_manual_locale() {
+            let maybe_client = ClientBuilder::new().version("0.0.0").locale(Locale::PtBr).build();
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().locale, Locale::PtBr);
+        }
     }
 
     mod requests {
         use super::*;
 
         #[test]
-        fn get_data_url_constructs_expected_baseurl() {
+        fn get_url_constructs_expected_data_url() {
+            let (_server, url, client) = create_mock_client();
+            assert_eq!(
+                client.get_url(&client.data_resource("./item.json")).unwrap().as_str(),
+                format!("{url}/cdn/0.0.0/data/en_US/item.json")
+            );
+        }
+
+        #[test]
+        fn data_resource_uses_configured_locale() {
             let (_server, url, client) = create_mock_client();
+            let client = client.with_locale(Locale::DeDe);
             assert_eq!(
-                client.get_data_url().unwrap().as_str(),
-                format!("{url}/cdn/0.0.0/data/en_US/")
+                client.get_url(&client.data_resource("item.json")).unwrap().as_str(),
+                format!("{url}/cdn/0.0.0/data/de_DE/item.json")
+            );
+        }
+
+        #[test]
+        fn data_resource_uses_pinned_version() {
+            let (_server, url, client) = create_mock_client();
+            let pinned = client.at_version("14.3.1");
+            assert_eq!(
+                pinned.get_url(&pinned.data_resource("item.json")).unwrap().as_str(),
+                format!("{url}/cdn/14.3.1/data/en_US/item.json")
+            );
+            assert_eq!(client.version, "0.0.0");
+        }
+
+        #[cfg(feature = "cdragon")]
+        #[test]
+        fn get_url_uses_pinned_cdragon_patch() {
+            let (_server, url, client) = create_mock_client();
+            let pinned = client.at_version("14.3.1");
+            let resource = endpoint::ArenaAugments.resource(&pinned.version, &pinned.locale);
+            assert_eq!(
+                pinned.get_url(&resource).unwrap().as_str(),
+                format!("{url}/14.3/cdragon/arena/en_us.json")
             );
         }
 
         #[test]
         fn get_data_err_if_server_unavailable() {
             let (_server, _url, client) = create_mock_client();
-            assert!(client.get_data::<String>("/fake-endpoint").is_err());
+            assert!(client.get_data::<String>(client.data_resource("fake-endpoint")).is_err());
         }
 
         #[test]
@@ -503,7 +980,159 @@ mod test {
                 .with_body(r#"no chance to deserialize this"#)
                 .create();
 
-            assert!(client.get_data::<String>("./data.json").is_err());
+            assert!(matches!(
+                client.get_data::<String>(client.data_resource("data.json")),
+                Err(ClientError::Deserialize { .. })
+            ));
+        }
+
+        #[test]
+        fn get_data_err_includes_url_and_path() {
+            let (mut server, url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"values": ["value", 1]}"#)
+                .create();
+
+            match client.get_data::<HashMap<String, Vec<String>>>(client.data_resource("data.json"))
+            {
+                Err(ClientError::Deserialize { url: failed_url, path, .. }) => {
+                    assert_eq!(failed_url, format!("{url}/cdn/0.0.0/data/en_US/data.json"));
+                    assert_eq!(path, "values[1]");
+                }
+                _ => panic!("expected a deserialize error"),
+            }
+        }
+
+        #[test]
+        fn champion_err_not_found_for_unknown_key() {
+            let (mut server, url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/Foo.json")
+                .with_status(403)
+                .create();
+
+            assert!(matches!(
+                client.champion("Foo"),
+                Err(ClientError::NotFound { url: failed_url })
+                    if failed_url == format!("{url}/cdn/0.0.0/data/en_US/champion/Foo.json")
+            ));
+        }
+
+        #[test]
+        fn champions_detailed_ok_with_partial_results() {
+            let (mut server, _url, client) = create_mock_client();
+            let _ahri = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/Ahri.json")
+                .with_status(200)
+                .with_body(champion_json("Ahri"))
+                .create();
+            let _foo = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/Foo.json")
+                .with_status(404)
+                .create();
+
+            let report = client.champions_detailed(&["Ahri", "Foo"]);
+
+            assert_eq!(report.fetched["Ahri"].name, "Ahri");
+            assert!(matches!(report.failed["Foo"], ClientError::NotFound { .. }));
+            assert!(!report.is_complete());
+        }
+
+        #[test]
+        fn fetch_shared_ok_reuses_memoized_data() {
+            let (mut server, _url, client) = create_mock_client();
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/feats.json")
+                .with_status(200)
+                .with_body(r#"{"a": 1}"#)
+                .expect(1)
+                .create();
+
+            let client =
+                Client { memo: Some(Arc::new(Memo::new(1, CachePolicy::default()))), ..client };
+            let endpoint = endpoint::DataEndpoint::<serde_json::Value>::new("feats.json");
+            let first = client.fetch_shared(&endpoint).unwrap();
+            let second = client.at_version("0.0.0").fetch_shared(&endpoint).unwrap();
+
+            assert!(Arc::ptr_eq(&first, &second));
+            mock.assert();
+        }
+
+        #[test]
+        fn typed_methods_ok_reuse_memoized_data() {
+            let (mut server, _url, client) = create_mock_client();
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/map.json")
+                .with_status(200)
+                .with_body(r#"{"version": "0.0.0", "data": {}}"#)
+                .expect(1)
+                .create();
+
+            let client =
+                Client { memo: Some(Arc::new(Memo::new(1, CachePolicy::default()))), ..client };
+
+            assert_eq!(client.maps().unwrap(), client.maps().unwrap());
+            mock.assert();
+        }
+
+        #[test]
+        fn get_data_err_includes_status() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock =
+                server.mock("GET", "/cdn/0.0.0/data/en_US/item.json").with_status(503).create();
+
+            assert!(matches!(client.items(), Err(ClientError::HttpStatus { status: 503, .. })));
+        }
+
+        #[test]
+        fn versions_ok_deserializes_to_patch_versions() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["14.3.1", "14.2.1", "lolpatch_3.7"]"#)
+                .create();
+
+            let versions = client.versions().unwrap();
+            assert_eq!(versions.len(), 3);
+            assert_eq!(versions.latest().unwrap().to_string(), "14.3.1");
+        }
+
+        #[test]
+        fn version_for_game_pins_matching_version() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["14.4.1", "14.3.1", "14.3.0"]"#)
+                .create();
+
+            assert_eq!(client.version_for_game("14.3.558.2130").unwrap().version, "14.3.1");
+            assert!(matches!(
+                client.version_for_game("13.1.1.1"),
+                Err(ClientError::NoMatchingVersion)
+            ));
+        }
+
+        #[test]
+        fn languages_ok_deserializes_to_locales() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/languages.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["en_US", "ko_KR", "xx_XX"]"#)
+                .create();
+
+            assert_eq!(
+                client.languages().unwrap(),
+                vec![Locale::EnUs, Locale::KoKr, Locale::Other("xx_XX".to_owned())]
+            );
         }
 
         #[test]
@@ -517,9 +1146,184 @@ mod test {
                 .create();
 
             assert_eq!(
-                client.get_data_ddragon::<Vec<String>>("./data.json").unwrap(),
+                client.get_data::<Vec<String>>(client.data_resource("data.json")).unwrap(),
+                vec!["value".to_owned()]
+            );
+        }
+
+        #[test]
+        fn fetch_ok_for_custom_endpoints() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/feats.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"data": {"1": "First Blood"}}"#)
+                .create();
+
+            let endpoint = endpoint::DataEndpoint::<serde_json::Value>::new("feats.json");
+            assert_eq!(client.fetch(&endpoint).unwrap()["data"]["1"], "First Blood");
+            assert_eq!(
+                client.fetch_raw(&endpoint).unwrap(),
+                br#"{"data": {"1": "First Blood"}}"#.to_vec()
+            );
+        }
+
+        #[test]
+        fn get_data_retries_transient_failures() {
+            let mut server = Server::new();
+            let unavailable = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(503)
+                .with_header("Retry-After", "0")
+                .expect(2)
+                .create();
+            let available = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["value"]"#)
+                .expect(1)
+                .create();
+
+            let policy = RetryPolicy::default().backoff(Duration::from_secs(60), Duration::MAX);
+            let client = ClientBuilder::new()
+                .server(&server.url())
+                .version("0.0.0")
+                .retry_policy(policy)
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                client.get_data::<Vec<String>>(client.data_resource("data.json")).unwrap(),
                 vec!["value".to_owned()]
             );
+            unavailable.assert();
+            available.assert();
+        }
+
+        #[test]
+        fn drift_report_lists_unknown_fields() {
+            #[derive(serde::Deserialize)]
+            struct Known {
+                #[allow(dead_code)]
+                known: u32,
+            }
+
+            let (mut server, url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"known": 1, "unknown": {"nested": true}}"#)
+                .create();
+
+            assert!(client.drift_report().is_none());
+            let client = Client { drift: Some(Default::default()), ..client };
+            client.fetch(&endpoint::DataEndpoint::<Known>::new("data.json")).unwrap();
+
+            let drift = client.at_version("14.3.1").drift_report().unwrap();
+            assert_eq!(
+                drift.to_string(),
+                format!("{url}/cdn/0.0.0/data/en_US/data.json\n  unknown\n")
+            );
+        }
+
+        #[test]
+        fn prefetch_skips_cached_endpoints() {
+            let mut server = Server::new();
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/cdn/0\.0\.0/data/en_US/[\w-]+\.json$".to_owned()))
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("{}")
+                .expect(DATA_ENDPOINTS.len())
+                .create();
+
+            let cache_dir = temp_dir().join("client-prefetch01");
+            let _ = remove_dir_all(&cache_dir);
+            let client = ClientBuilder::new()
+                .server(&server.url())
+                .cache(&cache_dir.to_string_lossy())
+                .version("0.0.0")
+                .build()
+                .unwrap();
+
+            let mut progress = vec![];
+            let first = client.prefetch(PrefetchPlan::default(), |p| progress.push(p.completed));
+            let second = client.prefetch(PrefetchPlan::default(), |_| {}).unwrap();
+
+            assert_eq!(first.unwrap().fetched.len(), DATA_ENDPOINTS.len());
+            assert_eq!(progress, (1..=DATA_ENDPOINTS.len()).collect::<Vec<_>>());
+            assert_eq!(second.skipped.len(), DATA_ENDPOINTS.len());
+            mock.assert();
+        }
+
+        #[test]
+        fn prefetch_reports_listings() {
+            let mut server = Server::new();
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(200)
+                .with_body(r#"{"format": "", "version": "", "data": {}, "keys": {}}"#)
+                .expect(1)
+                .create();
+
+            let cache_dir = temp_dir().join("client-prefetch02");
+            let _ = remove_dir_all(&cache_dir);
+            let client = ClientBuilder::new()
+                .server(&server.url())
+                .cache(&cache_dir.to_string_lossy())
+                .version("0.0.0")
+                .build()
+                .unwrap();
+
+            let plan = PrefetchPlan::default().data(false).champions(true);
+            let mut progress = vec![];
+            let first = client.prefetch(plan, |p| progress.push(p.url.to_owned())).unwrap();
+            let second = client.prefetch(plan, |_| {}).unwrap();
+
+            let listing = format!("{}/cdn/0.0.0/data/en_US/championFull.json", server.url());
+            assert_eq!(first.fetched, vec![listing.clone()]);
+            assert_eq!(progress, vec![listing.clone()]);
+            assert_eq!(second.skipped, vec![listing]);
+            mock.assert();
+        }
+
+        #[test]
+        fn prefetch_without_cache_reports_uncached() {
+            let (mut server, url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(200)
+                .with_body(r#"{"format": "", "version": "", "data": {}, "keys": {}}"#)
+                .create();
+
+            let plan = PrefetchPlan::default().data(false).champions(true);
+            let report = client.prefetch(plan, |_| {}).unwrap();
+
+            assert!(report.fetched.is_empty());
+            assert_eq!(
+                report.uncached,
+                vec![format!("{url}/cdn/0.0.0/data/en_US/championFull.json")]
+            );
+            assert!(!report.is_complete());
+        }
+
+        #[test]
+        fn prefetch_reports_failed_listings() {
+            let (mut server, url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(404)
+                .create();
+
+            let plan = PrefetchPlan::default().data(false).champions(true);
+            let report = client.prefetch(plan, |_| panic!("nothing should be fetched")).unwrap();
+
+            assert!(report.fetched.is_empty());
+            assert_eq!(report.failed.len(), 1);
+            assert_eq!(report.failed[0].0, format!("{url}/cdn/0.0.0/data/en_US/championFull.json"));
         }
     }
 }
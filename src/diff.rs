@@ -0,0 +1,382 @@
+#![warn(missing_docs)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::models::{
+    ChampionsFull, Items, Runes, SummonerSpells,
+    champion::{ChampionStats, Spell},
+    items::{Gold, Item, ItemStat},
+    runes::RuneElement,
+    summoner_spells::SummonerSpell,
+};
+
+/// A value that differs between the old and the new snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change<T> {
+    /// The value in the old snapshot.
+    pub old: T,
+    /// The value in the new snapshot.
+    pub new: T,
+}
+
+impl<T: PartialEq + Clone> Change<T> {
+    fn between(old: &T, new: &T) -> Option<Self> {
+        (old != new).then(|| Self { old: old.clone(), new: new.clone() })
+    }
+}
+
+/// Every difference between two snapshots of a keyed collection, e.g. all
+/// champions in two different patches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diff<C> {
+    /// Keys that only exist in the new snapshot, sorted.
+    pub added: Vec<String>,
+    /// Keys that only exist in the old snapshot, sorted.
+    pub removed: Vec<String>,
+    /// Details for every key that exists in both snapshots but changed.
+    pub changed: BTreeMap<String, C>,
+}
+
+impl<C> Diff<C> {
+    /// Whether the snapshots were identical, as far as the diff is concerned.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Changes to a single champion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChampionChange {
+    /// Changed base stats, keyed by the ddragon stat name (e.x. `hpperlevel`).
+    /// A stat that was added or removed has `None` on the missing side, apart
+    /// from those with a field on [ChampionStats], which read as `0.0`.
+    pub stats: BTreeMap<String, Change<Option<f64>>>,
+    /// Changes to the champion's spells, keyed by spell ID.
+    pub spells: Diff<SpellChange>,
+}
+
+/// Changes to a champion spell or summoner spell. Each field is `None` if
+/// it did not change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellChange {
+    /// Cooldown per rank, in seconds.
+    pub cooldown: Option<Change<Vec<f64>>>,
+    /// Resource cost per rank.
+    pub cost: Option<Change<Vec<i64>>>,
+    /// Range per rank.
+    pub range: Option<Change<Vec<i64>>>,
+}
+
+/// Changes to a single item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemChange {
+    /// The item's price, if it changed.
+    pub gold: Option<Change<Gold>>,
+    /// The components the item is built from, if they changed.
+    pub from: Option<Change<Vec<String>>>,
+    /// The items this item builds into, if they changed.
+    pub into: Option<Change<Vec<String>>>,
+    /// Changed stats, keyed by the ddragon stat name (e.x. `FlatHPPoolMod`).
+    pub stats: BTreeMap<String, Change<Option<f64>>>,
+}
+
+/// Changes to a single rune.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuneChange {
+    /// The rune's name, if it changed.
+    pub name: Option<Change<String>>,
+    /// The rune's short description, if it changed.
+    pub short_desc: Option<Change<String>>,
+    /// The rune's full description, if it changed.
+    pub long_desc: Option<Change<String>>,
+}
+
+/// Compares every champion between two patches.
+pub fn champions(old: &ChampionsFull, new: &ChampionsFull) -> Diff<ChampionChange> {
+    diff_by_key(&old.data, &new.data, |old, new| {
+        let change = ChampionChange {
+            stats: stat_changes(&stats_by_name(&old.stats), &stats_by_name(&new.stats)),
+            spells: diff_by_key(&spells_by_id(&old.spells), &spells_by_id(&new.spells), |o, n| {
+                spell_change(o, n)
+            }),
+        };
+        (!change.stats.is_empty() || !change.spells.is_empty()).then_some(change)
+    })
+}
+
+/// Compares every item between two patches.
+pub fn items(old: &Items, new: &Items) -> Diff<ItemChange> {
+    diff_by_key(&old.data, &new.data, |old: &Item, new: &Item| {
+        let change = ItemChange {
+            gold: Change::between(&old.gold, &new.gold),
+            from: Change::between(
+                &old.from.clone().unwrap_or_default(),
+                &new.from.clone().unwrap_or_default(),
+            ),
+            into: Change::between(
+                &old.into.clone().unwrap_or_default(),
+                &new.into.clone().unwrap_or_default(),
+            ),
+            stats: stat_changes(&item_stats_by_name(&old.stats), &item_stats_by_name(&new.stats)),
+        };
+        (change.gold.is_some()
+            || change.from.is_some()
+            || change.into.is_some()
+            || !change.stats.is_empty())
+        .then_some(change)
+    })
+}
+
+/// Compares every rune between two patches. Runes are keyed by their numeric
+/// ID, regardless of which tree or slot they are in.
+pub fn runes(old: &Runes, new: &Runes) -> Diff<RuneChange> {
+    diff_by_key(&runes_by_id(old), &runes_by_id(new), |old, new| {
+        let change = RuneChange {
+            name: Change::between(&old.name, &new.name),
+            short_desc: Change::between(&old.short_desc, &new.short_desc),
+            long_desc: Change::between(&old.long_desc, &new.long_desc),
+        };
+        (change.name.is_some() || change.short_desc.is_some() || change.long_desc.is_some())
+            .then_some(change)
+    })
+}
+
+/// Compares every summoner spell between two patches.
+pub fn summoner_spells(old: &SummonerSpells, new: &SummonerSpells) -> Diff<SpellChange> {
+    diff_by_key(&old.data, &new.data, |old: &SummonerSpell, new: &SummonerSpell| {
+        let change = SpellChange {
+            cooldown: Change::between(&old.cooldown, &new.cooldown),
+            cost: Change::between(&old.cost, &new.cost),
+            range: Change::between(&old.range, &new.range),
+        };
+        change.has_changes().then_some(change)
+    })
+}
+
+impl SpellChange {
+    fn has_changes(&self) -> bool {
+        self.cooldown.is_some() || self.cost.is_some() || self.range.is_some()
+    }
+}
+
+fn spell_change(old: &Spell, new: &Spell) -> Option<SpellChange> {
+    let change = SpellChange {
+        cooldown: Change::between(&old.cooldown, &new.cooldown),
+        cost: Change::between(&old.cost, &new.cost),
+        range: Change::between(&old.range, &new.range),
+    };
+    change.has_changes().then_some(change)
+}
+
+fn spells_by_id(spells: &[Spell]) -> HashMap<String, &Spell> {
+    spells.iter().map(|s| (s.id.clone(), s)).collect()
+}
+
+fn runes_by_id(runes: &Runes) -> HashMap<String, &RuneElement> {
+    runes
+        .iter()
+        .flat_map(|tree| tree.slots.iter())
+        .flat_map(|slot| slot.runes.iter())
+        .map(|rune| (rune.id.to_string(), rune))
+        .collect()
+}
+
+fn stats_by_name(stats: &ChampionStats) -> HashMap<String, f64> {
+    let fields = stats.fields().into_iter().map(|(name, value)| (name.to_owned(), value));
+    fields.chain(stats.other.iter().map(|(name, value)| (name.clone(), *value))).collect()
+}
+
+fn item_stats_by_name(stats: &HashMap<ItemStat, f64>) -> HashMap<String, f64> {
+    stats.iter().map(|(stat, value)| (stat.key().to_owned(), *value)).collect()
+}
+
+fn stat_changes(
+    old: &HashMap<String, f64>,
+    new: &HashMap<String, f64>,
+) -> BTreeMap<String, Change<Option<f64>>> {
+    old.keys()
+        .chain(new.keys())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .filter_map(|key| {
+            let change = Change::between(&old.get(key).copied(), &new.get(key).copied())?;
+            Some((key.clone(), change))
+        })
+        .collect()
+}
+
+fn diff_by_key<T, C>(
+    old: &HashMap<String, T>,
+    new: &HashMap<String, T>,
+    compare: impl Fn(&T, &T) -> Option<C>,
+) -> Diff<C> {
+    let mut added: Vec<String> = new.keys().filter(|k| !old.contains_key(*k)).cloned().collect();
+    let mut removed: Vec<String> = old.keys().filter(|k| !new.contains_key(*k)).cloned().collect();
+    added.sort();
+    removed.sort();
+
+    let changed = old
+        .iter()
+        .filter_map(|(key, old_value)| {
+            let change = compare(old_value, new.get(key)?)?;
+            Some((key.clone(), change))
+        })
+        .collect();
+
+    Diff { added, removed, changed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::models::{
+        Champion,
+        runes::{Rune, Slot},
+    };
+
+    fn spell(id: &str, cooldown: Vec<f64>) -> Spell {
+        Spell { cooldown, cost: vec![50; 5], range: vec![600; 5], ..fixtures::spell(id) }
+    }
+
+    fn stats(hp: f64, armor: f64) -> ChampionStats {
+        ChampionStats { hp, armor, ..Default::default() }
+    }
+
+    fn champion(stats: ChampionStats, spells: Vec<Spell>) -> Champion {
+        Champion { stats, spells, ..fixtures::champion("") }
+    }
+
+    fn champions(champions: Vec<(&str, Champion)>) -> ChampionsFull {
+        fixtures::champions(champions)
+    }
+
+    fn item(total: i64, from: Option<Vec<&str>>, stats: &[(&str, f64)]) -> Item {
+        Item {
+            gold: Gold { base: total, total, sell: total / 2, purchasable: true },
+            stats: stats.iter().map(|(k, v)| (ItemStat::from(k.to_string()), *v)).collect(),
+            from: from.map(|f| f.into_iter().map(|s| s.to_owned()).collect()),
+            ..fixtures::item(total)
+        }
+    }
+
+    fn items(items: Vec<(&str, Item)>) -> Items {
+        fixtures::items(items)
+    }
+
+    fn rune(id: i64, long_desc: &str) -> RuneElement {
+        RuneElement {
+            id,
+            key: String::new(),
+            icon: String::new(),
+            name: String::new(),
+            short_desc: String::new(),
+            long_desc: long_desc.to_owned(),
+        }
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_changes() {
+        let snapshot = champions(vec![("Ahri", champion(stats(500.0, 0.0), vec![]))]);
+        assert!(super::champions(&snapshot, &snapshot).is_empty());
+    }
+
+    #[test]
+    fn detects_added_and_removed_champions() {
+        let old = champions(vec![("Ahri", champion(ChampionStats::default(), vec![]))]);
+        let new = champions(vec![("Zed", champion(ChampionStats::default(), vec![]))]);
+        let diff = super::champions(&old, &new);
+
+        assert_eq!(diff.added, vec!["Zed"]);
+        assert_eq!(diff.removed, vec!["Ahri"]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn detects_champion_stat_changes() {
+        let old = champions(vec![("Ahri", champion(stats(500.0, 20.0), vec![]))]);
+        let new = champions(vec![("Ahri", champion(stats(520.0, 20.0), vec![]))]);
+        let diff = super::champions(&old, &new);
+
+        let stats = &diff.changed["Ahri"].stats;
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats["hp"], Change { old: Some(500.0), new: Some(520.0) });
+    }
+
+    #[test]
+    fn detects_added_champion_stats() {
+        let mut added = stats(500.0, 20.0);
+        added.other.insert("tenacity".to_owned(), 5.0);
+        let old = champions(vec![("Ahri", champion(stats(500.0, 20.0), vec![]))]);
+        let new = champions(vec![("Ahri", champion(added, vec![]))]);
+        let diff = super::champions(&old, &new);
+
+        let stats = &diff.changed["Ahri"].stats;
+        assert_eq!(stats["tenacity"], Change { old: None, new: Some(5.0) });
+    }
+
+    #[test]
+    fn detects_spell_cooldown_changes() {
+        let old = champions(vec![(
+            "Ahri",
+            champion(ChampionStats::default(), vec![spell("AhriQ", vec![7.0; 5])]),
+        )]);
+        let new = champions(vec![(
+            "Ahri",
+            champion(ChampionStats::default(), vec![spell("AhriQ", vec![6.0; 5])]),
+        )]);
+        let diff = super::champions(&old, &new);
+
+        let spell = &diff.changed["Ahri"].spells.changed["AhriQ"];
+        assert_eq!(spell.cooldown, Some(Change { old: vec![7.0; 5], new: vec![6.0; 5] }));
+        assert_eq!(spell.cost, None);
+        assert_eq!(spell.range, None);
+    }
+
+    #[test]
+    fn detects_item_gold_and_recipe_changes() {
+        let old = items(vec![("3031", item(3400, Some(vec!["1038"]), &[]))]);
+        let new = items(vec![("3031", item(3450, Some(vec!["1038", "1018"]), &[]))]);
+        let diff = super::items(&old, &new);
+
+        let change = &diff.changed["3031"];
+        assert_eq!(change.gold.as_ref().map(|g| (g.old.total, g.new.total)), Some((3400, 3450)));
+        assert_eq!(
+            change.from,
+            Some(Change {
+                old: vec!["1038".to_owned()],
+                new: vec!["1038".to_owned(), "1018".to_owned()]
+            })
+        );
+        assert_eq!(change.into, None);
+    }
+
+    #[test]
+    fn detects_added_item_stats() {
+        let old = items(vec![("1001", item(300, None, &[]))]);
+        let new = items(vec![("1001", item(300, None, &[("FlatMovementSpeedMod", 25.0)]))]);
+        let diff = super::items(&old, &new);
+
+        assert_eq!(
+            diff.changed["1001"].stats["FlatMovementSpeedMod"],
+            Change { old: None, new: Some(25.0) }
+        );
+    }
+
+    #[test]
+    fn detects_rune_description_changes() {
+        let tree = |long_desc: &str| Rune {
+            id: 8000,
+            key: String::new(),
+            icon: String::new(),
+            name: String::new(),
+            slots: vec![Slot { runes: vec![rune(8005, long_desc)] }],
+        };
+        let diff = super::runes(&vec![tree("old")], &vec![tree("new")]);
+
+        assert_eq!(
+            diff.changed["8005"].long_desc,
+            Some(Change { old: "old".to_owned(), new: "new".to_owned() })
+        );
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The fields a client has received that none of the models know about, and
+/// would otherwise have been silently dropped. Only collected when drift
+/// reporting is enabled on the client's builder.
+///
+/// Fields missing from the data aren't listed here, as they already fail with
+/// [ClientError::Deserialize](crate::ClientError::Deserialize). Neither are
+/// champion stats without a field of their own, as they are kept in
+/// [ChampionStats::other](crate::models::champion::ChampionStats::other)
+/// rather than dropped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriftReport {
+    unknown_fields: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl DriftReport {
+    /// Whether every field received so far was known.
+    pub fn is_empty(&self) -> bool {
+        self.unknown_fields.is_empty()
+    }
+
+    /// The unknown fields, by the URL of the resource they were found in.
+    /// Each field is a path into the document, e.x. `data.Ahri.newField`.
+    pub fn unknown_fields(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.unknown_fields
+    }
+
+    pub(crate) fn record(&mut self, url: String, fields: Vec<String>) {
+        if !fields.is_empty() {
+            self.unknown_fields.entry(url).or_default().extend(fields);
+        }
+    }
+}
+
+/// Lists each URL, followed by its unknown fields.
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (url, fields) in &self.unknown_fields {
+            writeln!(f, "{url}")?;
+            for field in fields {
+                writeln!(f, "  {field}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_fields_by_url() {
+        let mut report = DriftReport::default();
+        report.record("item.json".to_owned(), vec![]);
+        assert!(report.is_empty());
+
+        report.record("item.json".to_owned(), vec!["data.1001.b".to_owned()]);
+        report.record("item.json".to_owned(), vec!["data.1001.a".to_owned()]);
+        assert_eq!(report.to_string(), "item.json\n  data.1001.a\n  data.1001.b\n");
+    }
+}
@@ -0,0 +1,404 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+use std::{fmt, marker::PhantomData, sync::Mutex};
+
+#[cfg(feature = "cdragon")]
+use crate::models::versions::patch_of;
+use crate::{
+    ClientError,
+    drift::DriftReport,
+    models::{self, Locale, champion::ChampionWrapper},
+    source::Resource,
+};
+
+/// Describes a piece of data either client can return: which resource it is
+/// read from, and how the deserialized data becomes the returned value.
+///
+/// Both [Client](crate::Client) and [AsyncClient](crate::AsyncClient) build
+/// their methods from the endpoints in this module, so the two clients always
+/// offer the same data. Implementing this trait for your own type lets either
+/// client's `fetch` method return it.
+pub trait Endpoint {
+    /// The type the resource deserializes to.
+    type Data: DeserializeOwned;
+    /// The type returned to the caller.
+    type Output;
+
+    /// The resource holding the data for a version and locale.
+    fn resource(&self, version: &str, locale: &Locale) -> Resource;
+
+    /// Turns the deserialized resource into the returned value.
+    fn output(&self, data: Self::Data) -> Result<Self::Output, ClientError>;
+}
+
+/// Calls `$callback!` with every data endpoint, as a list of
+/// `(method, Endpoint, kind, path, Output)`.
+macro_rules! data_endpoints {
+    ($callback:ident) => {
+        $callback! {
+            (challenges, Challenges, "challenge", "challenges", models::Challenges),
+            (champions, Champions, "champion", "champion", models::Champions),
+            (champions_full, ChampionsFull, "complete champion", "championFull",
+                models::ChampionsFull),
+            (items, Items, "item", "item", models::Items),
+            (maps, Maps, "map", "map", models::Maps),
+            (mission_assets, MissionAssets, "mission asset", "mission-assets",
+                models::MissionAssets),
+            (profile_icons, ProfileIcons, "profile icon", "profileicon", models::ProfileIcons),
+            (runes, Runes, "rune", "runesReforged", models::Runes),
+            (spell_buffs, SpellBuffs, "spell buff", "spellbuffs", models::SpellBuffs),
+            (summoner_spells, SummonerSpells, "summoner_spells", "summoner",
+                models::SummonerSpells),
+            (translations, Translations, "translation", "language", models::Translations),
+            (tft_arenas, TftArenas, "TFT arena", "tft-arena", models::tft::Arenas),
+            (tft_augments, TftAugments, "TFT augment", "tft-augments", models::tft::Augments),
+            (tft_champions, TftChampions, "TFT champion", "tft-champion", models::tft::Champions),
+            (tft_hero_augments, TftHeroAugments, "TFT hero augment", "tft-hero-augments",
+                models::tft::HeroAugments),
+            (tft_items, TftItems, "TFT item", "tft-item", models::tft::Items),
+            (tft_queues, TftQueues, "TFT queue", "tft-queues", models::tft::Queues),
+            (tft_regalia, TftRegalia, "TFT regalia", "tft-regalia", models::tft::Regalia),
+            (tft_tacticians, TftTacticians, "TFT tactician", "tft-tactician",
+                models::tft::Tacticians),
+            (tft_traits, TftTraits, "TFT trait", "tft-trait", models::tft::Traits),
+        }
+    };
+}
+
+pub(crate) use data_endpoints;
+
+macro_rules! create_data_endpoints {
+    ($(($method:ident, $name:ident, $kind:literal, $path:literal, $ret:ty)),+ $(,)?) => {
+        $(
+            #[doc = concat!("Returns ", $kind, " data, from `", $path, ".json`.")]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            pub struct $name;
+
+            impl Endpoint for $name {
+                type Data = $ret;
+                type Output = $ret;
+
+                fn resource(&self, version: &str, locale: &Locale) -> Resource {
+                    data_resource(version, locale, concat!($path, ".json"))
+                }
+
+                fn output(&self, data: Self::Data) -> Result<Self::Output, ClientError> {
+                    Ok(data)
+                }
+            }
+        )+
+
+        /// Every data endpoint, relative to the data directory.
+        pub(crate) const DATA_ENDPOINTS: &[&str] = &[$(concat!($path, ".json")),+];
+    };
+}
+
+data_endpoints!(create_data_endpoints);
+
+/// A data resource, relative to the data directory of a version and locale.
+pub(crate) fn data_resource(version: &str, locale: &Locale, path: &str) -> Resource {
+    Resource::Data {
+        version: version.to_owned(),
+        locale: locale.clone(),
+        path: path.trim_start_matches("./").to_owned(),
+    }
+}
+
+/// Deserializes a fetched resource, keeping track of where in the document
+/// deserializing failed. Any fields the model doesn't know about are recorded
+/// in `drift`, if given. Unknown fields are only tracked when they will be
+/// recorded, as doing so allocates for each of them.
+pub(crate) fn deserialize<T: DeserializeOwned>(
+    data: &[u8],
+    url: impl Fn() -> String,
+    drift: Option<&Mutex<DriftReport>>,
+) -> Result<T, ClientError> {
+    let mut unknown_fields = vec![];
+    let mut deserializer = serde_json::Deserializer::from_slice(data);
+    let value = match drift {
+        Some(_) => {
+            let mut track = |path: serde_ignored::Path| unknown_fields.push(path.to_string());
+            deserialize_tracked(serde_ignored::Deserializer::new(&mut deserializer, &mut track))
+        }
+        None => deserialize_tracked(&mut deserializer),
+    }
+    .and_then(|value| deserializer.end().map(|_| value).map_err(|e| (".".to_owned(), e)))
+    .map_err(|(path, source)| ClientError::Deserialize { url: url(), path, source })?;
+
+    if let Some(drift) = drift {
+        drift.lock().unwrap().record(url(), unknown_fields);
+    }
+    Ok(value)
+}
+
+/// Deserializes a value, returning where in the document it failed if it
+/// does.
+fn deserialize_tracked<'de, T, D>(deserializer: D) -> Result<T, (String, serde_json::Error)>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de, Error = serde_json::Error>,
+{
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|e| (e.path().to_string(), e.into_inner()))
+}
+
+/// Returns any data file, for data this crate doesn't have an endpoint for
+/// yet. The path is relative to the data directory of the client's version
+/// and locale.
+///
+/// ```no_run
+/// use ddragon::{Client, endpoint::DataEndpoint};
+///
+/// let api = Client::new("./cache").unwrap();
+/// let feats: serde_json::Value = api.fetch(&DataEndpoint::new("feats.json")).unwrap();
+/// ```
+pub struct DataEndpoint<T> {
+    path: String,
+    data: PhantomData<fn() -> T>,
+}
+
+impl<T> DataEndpoint<T> {
+    /// Creates an endpoint for the data file at `path`, e.x. `item.json`.
+    pub fn new(path: &str) -> Self {
+        Self { path: path.to_owned(), data: PhantomData }
+    }
+}
+
+impl<T> Clone for DataEndpoint<T> {
+    fn clone(&self) -> Self {
+        Self::new(&self.path)
+    }
+}
+
+impl<T> fmt::Debug for DataEndpoint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataEndpoint").field("path", &self.path).finish()
+    }
+}
+
+impl<T: DeserializeOwned> Endpoint for DataEndpoint<T> {
+    type Data = T;
+    type Output = T;
+
+    fn resource(&self, version: &str, locale: &Locale) -> Resource {
+        data_resource(version, locale, &self.path)
+    }
+
+    fn output(&self, data: Self::Data) -> Result<Self::Output, ClientError> {
+        Ok(data)
+    }
+}
+
+/// Returns every version ddragon has published, newest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Versions;
+
+impl Endpoint for Versions {
+    type Data = models::Versions;
+    type Output = models::Versions;
+
+    fn resource(&self, _version: &str, _locale: &Locale) -> Resource {
+        Resource::Versions
+    }
+
+    fn output(&self, data: Self::Data) -> Result<Self::Output, ClientError> {
+        Ok(data)
+    }
+}
+
+/// Returns the list of languages ddragon publishes data in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Languages;
+
+impl Endpoint for Languages {
+    type Data = Vec<Locale>;
+    type Output = Vec<Locale>;
+
+    fn resource(&self, _version: &str, _locale: &Locale) -> Resource {
+        Resource::Languages
+    }
+
+    fn output(&self, data: Self::Data) -> Result<Self::Output, ClientError> {
+        Ok(data)
+    }
+}
+
+/// Returns data for a single champion, by the key property on the
+/// [Champion](models::Champion) struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Champion {
+    key: String,
+}
+
+impl Champion {
+    /// Creates an endpoint for the champion with the given key, e.x.
+    /// `MonkeyKing` for Wukong.
+    pub fn new(key: &str) -> Self {
+        Self { key: key.to_owned() }
+    }
+}
+
+impl Endpoint for Champion {
+    type Data = ChampionWrapper;
+    type Output = models::Champion;
+
+    fn resource(&self, version: &str, locale: &Locale) -> Resource {
+        data_resource(version, locale, &format!("champion/{}.json", self.key))
+    }
+
+    fn output(&self, mut data: Self::Data) -> Result<Self::Output, ClientError> {
+        data.data.remove(&self.key).ok_or(ClientError::NoChampionData)
+    }
+}
+
+/// [cdragon] Returns augment data for the Arena game mode.
+#[cfg(feature = "cdragon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cdragon")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ArenaAugments;
+
+#[cfg(feature = "cdragon")]
+impl Endpoint for ArenaAugments {
+    type Data = models::cdragon::augments::AugmentsResponse;
+    type Output = Vec<models::Augment>;
+
+    fn resource(&self, version: &str, locale: &Locale) -> Resource {
+        Resource::CDragon {
+            patch: patch_of(version).to_owned(),
+            path: format!("arena/{}.json", locale.as_str().to_lowercase()),
+        }
+    }
+
+    fn output(&self, data: Self::Data) -> Result<Self::Output, ClientError> {
+        Ok(data.augments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn data_endpoints_use_version_and_locale() {
+        assert_eq!(
+            Items.resource("14.3.1", &Locale::KoKr),
+            Resource::Data {
+                version: "14.3.1".to_owned(),
+                locale: Locale::KoKr,
+                path: "item.json".to_owned()
+            }
+        );
+        assert_eq!(Versions.resource("14.3.1", &Locale::KoKr), Resource::Versions);
+        assert_eq!(
+            DataEndpoint::<()>::new("./item.json").resource("14.3.1", &Locale::KoKr),
+            Items.resource("14.3.1", &Locale::KoKr)
+        );
+    }
+
+    #[test]
+    fn champion_output_requires_matching_key() {
+        let wrapper = ChampionWrapper {
+            format: "standAloneComplex".to_owned(),
+            version: "14.3.1".to_owned(),
+            data: HashMap::new(),
+        };
+
+        assert!(matches!(Champion::new("Ahri").output(wrapper), Err(ClientError::NoChampionData)));
+    }
+
+    #[test]
+    fn deserialize_err_includes_path() {
+        let result = deserialize::<HashMap<String, Vec<u32>>>(
+            br#"{"a": [1, "two"]}"#,
+            || "url".to_owned(),
+            None,
+        );
+
+        match result {
+            Err(ClientError::Deserialize { url, path, .. }) => {
+                assert_eq!(url, "url");
+                assert_eq!(path, "a[1]");
+            }
+            _ => panic!("expected a deserialize error"),
+        }
+        assert!(deserialize::<Vec<u32>>(b"[1] [2]", String::new, None).is_err());
+    }
+
+    #[test]
+    fn deserialize_records_unknown_fields() {
+        let drift = Mutex::new(DriftReport::default());
+        let data = br#"{"format": "full", "version": "14.3.1", "data": {}, "new": [1]}"#;
+        let wrapper: ChampionWrapper =
+            deserialize(data, || "url".to_owned(), Some(&drift)).unwrap();
+
+        assert!(wrapper.data.is_empty());
+        let drift = drift.into_inner().unwrap();
+        assert_eq!(drift.unknown_fields()["url"].iter().collect::<Vec<_>>(), vec!["new"]);
+    }
+
+    #[cfg(feature = "cdragon")]
+    #[test]
+    fn arena_augments_use_patch_and_lowercase_locale() {
+        assert_eq!(
+            ArenaAugments.resource("14.3.1", &Locale::EnUs),
+            Resource::CDragon { patch: "14.3".to_owned(), path: "arena/en_us.json".to_owned() }
+        );
+    }
+
+    #[cfg(all(feature = "sync", any(feature = "async", feature = "async-rustls")))]
+    mod parity {
+        use super::*;
+        use crate::{AsyncClientBuilder, ClientBuilder, source::DataSource};
+        use std::sync::{Arc, Mutex};
+
+        /// Records every resource requested from it, without serving any.
+        #[derive(Clone, Default)]
+        struct RecordingSource(Arc<Mutex<Vec<Resource>>>);
+
+        impl DataSource for RecordingSource {
+            fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+                self.0.lock().unwrap().push(resource.clone());
+                Err(ClientError::NotFound { url: resource.path() })
+            }
+        }
+
+        #[tokio::test]
+        async fn clients_request_the_same_resources() {
+            let (sync_source, async_source) =
+                (RecordingSource::default(), RecordingSource::default());
+            let client =
+                ClientBuilder::new().version("14.3.1").source(sync_source.clone()).build().unwrap();
+            let async_client = AsyncClientBuilder::new()
+                .version("14.3.1")
+                .source(async_source.clone())
+                .build()
+                .await
+                .unwrap();
+
+            macro_rules! call_both {
+                ($(($method:ident $($rest:tt)*)),+ $(,)?) => {
+                    $(
+                        assert!(client.$method().is_err());
+                        assert!(async_client.$method().await.is_err());
+                    )+
+                };
+            }
+            data_endpoints!(call_both);
+            call_both!((versions), (languages));
+            #[cfg(feature = "cdragon")]
+            call_both!((arena_augments));
+            assert!(client.champion("Ahri").is_err());
+            assert!(async_client.champion("Ahri").await.is_err());
+
+            let requested = sync_source.0.lock().unwrap().clone();
+            assert_eq!(requested, *async_source.0.lock().unwrap());
+            assert_eq!(
+                requested.len(),
+                DATA_ENDPOINTS.len() + 3 + cfg!(feature = "cdragon") as usize
+            );
+        }
+    }
+}
@@ -24,9 +24,43 @@ pub enum ClientError {
     /// Indicates a request failed, for the same reasons any `reqwest-middlware`
     /// request may fail.
     AsyncMiddlewareRequest(#[from] reqwest_middleware::Error),
+    #[error("Could not access the cache.")]
+    /// Indicates reading from or writing to a cache directory failed.
+    Cache(#[from] cacache::Error),
     #[error("Could not parse JSON data.")]
     /// Indicates a failed attempt at parsing JSON data.
     Parse(#[from] std::io::Error),
+    #[error("Could not read {}.", path.display())]
+    /// Indicates a file or directory backing a data source couldn't be read,
+    /// for any reason other than it not existing.
+    Io {
+        /// The file or directory that was being read.
+        path: std::path::PathBuf,
+        /// Why reading failed.
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Could not deserialize {url} at `{path}`.")]
+    /// Indicates a resource was fetched, but doesn't match the shape of the
+    /// model it was deserialized into.
+    Deserialize {
+        /// The URL of the resource that was requested.
+        url: String,
+        /// Where in the document deserializing failed, e.x. `data.Ahri.stats`.
+        path: String,
+        /// Why deserializing failed.
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("{url} responded with status {status}.")]
+    /// Indicates the server answered a request with an error status, other
+    /// than one meaning the resource doesn't exist.
+    HttpStatus {
+        /// The URL that was requested.
+        url: String,
+        /// The HTTP status code of the response.
+        status: u16,
+    },
     #[cfg(feature = "image")]
     #[error("Could not parse image data.")]
     /// Indicates that attempting to convert bytes to a [image::DynamicImage]
@@ -36,6 +70,28 @@ pub enum ClientError {
     /// Indicates during instantiation that the version lists provided by the
     /// ddragon API was empty.
     NoLatestVersion,
+    #[error("Could not find a ddragon version matching the game version.")]
+    /// Indicates no published ddragon version belongs to the same patch as a
+    /// game version.
+    NoMatchingVersion,
+    #[error("{url} is not cached, and the client is offline.")]
+    /// Indicates an offline client was asked for data that isn't in the cache.
+    NotCached {
+        /// The URL that was requested.
+        url: String,
+    },
+    #[error("An offline client needs a cache directory or data source to read from.")]
+    /// Indicates a client was configured to be offline, but given nothing to
+    /// serve data from instead of the network.
+    OfflineWithoutCache,
+    #[error("{url} could not be found.")]
+    /// Indicates the requested resource doesn't exist, either on the server or
+    /// in the data source. Requesting a champion with an unknown key fails
+    /// with this.
+    NotFound {
+        /// The location of the resource that was requested.
+        url: String,
+    },
     #[error("Specific champion data could not be parsed.")]
     /// Indicates data for the requested champion couldn't be found in the
     /// parsed document.
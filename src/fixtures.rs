@@ -0,0 +1,126 @@
+//! Builders for minimal models, shared by the tests across the crate. Every
+//! field not given is empty, so tests only spell out what they check.
+
+use std::collections::HashMap;
+
+use crate::models::{
+    Champion, ChampionsFull, Items,
+    champion::{ChampionStats, Info, Passive, Spell},
+    items::{Gold, Item},
+    shared::Image,
+};
+
+pub(crate) fn image() -> Image {
+    Image {
+        full: String::new(),
+        sprite: String::new(),
+        group: String::new(),
+        x: 0,
+        y: 0,
+        w: 0,
+        h: 0,
+    }
+}
+
+/// A spell with five ranks.
+pub(crate) fn spell(id: &str) -> Spell {
+    Spell {
+        id: id.to_owned(),
+        name: id.to_owned(),
+        description: String::new(),
+        tooltip: String::new(),
+        leveltip: None,
+        maxrank: 5,
+        cooldown: vec![],
+        cooldown_burn: String::new(),
+        cost: vec![],
+        cost_burn: String::new(),
+        effect: vec![],
+        effect_burn: vec![],
+        cost_type: String::new(),
+        maxammo: String::new(),
+        range: vec![],
+        range_burn: String::new(),
+        image: image(),
+        resource: None,
+    }
+}
+
+pub(crate) fn champion(key: &str) -> Champion {
+    Champion {
+        id: key.to_owned(),
+        key: "0".to_owned(),
+        name: key.to_owned(),
+        title: String::new(),
+        image: image(),
+        skins: vec![],
+        lore: String::new(),
+        blurb: String::new(),
+        allytips: vec![],
+        enemytips: vec![],
+        tags: vec![],
+        partype: String::new(),
+        info: Info { attack: 0, defense: 0, magic: 0, difficulty: 0 },
+        stats: ChampionStats::default(),
+        spells: vec![],
+        passive: Passive { name: String::new(), description: String::new(), image: image() },
+    }
+}
+
+pub(crate) fn champions<'a>(
+    champions: impl IntoIterator<Item = (&'a str, Champion)>,
+) -> ChampionsFull {
+    ChampionsFull {
+        format: String::new(),
+        version: String::new(),
+        data: champions.into_iter().map(|(key, champion)| (key.to_owned(), champion)).collect(),
+        keys: HashMap::new(),
+    }
+}
+
+/// The `champion/<key>.json` resource for a single champion.
+#[cfg(any(feature = "sync", feature = "async-base"))]
+pub(crate) fn champion_json(key: &str) -> String {
+    let wrapper = crate::models::champion::ChampionWrapper {
+        format: String::new(),
+        version: String::new(),
+        data: HashMap::from([(key.to_owned(), champion(key))]),
+    };
+    serde_json::to_string(&wrapper).unwrap()
+}
+
+/// An item costing `base` gold.
+pub(crate) fn item(base: i64) -> Item {
+    Item {
+        name: String::new(),
+        description: String::new(),
+        colloq: String::new(),
+        plaintext: String::new(),
+        into: None,
+        image: image(),
+        gold: Gold { base, total: base, sell: 0, purchasable: true },
+        tags: vec![],
+        maps: HashMap::new(),
+        stats: HashMap::new(),
+        in_store: None,
+        from: None,
+        effect: None,
+        depth: None,
+        consumed: None,
+        stacks: None,
+        hide_from_all: None,
+        consume_on_full: None,
+        required_champion: None,
+        required_ally: None,
+        special_recipe: None,
+    }
+}
+
+pub(crate) fn items<'a>(items: impl IntoIterator<Item = (&'a str, Item)>) -> Items {
+    Items {
+        version: String::new(),
+        data: items.into_iter().map(|(id, item)| (id.to_owned(), item)).collect(),
+        groups: vec![],
+        tree: vec![],
+    }
+}
@@ -4,16 +4,65 @@
 /// Contains the structs that can be returned by the client API.
 pub mod models;
 
+/// Contains functions for comparing data between two patches.
+pub mod diff;
+
+/// Contains a parser and renderers for the rich text in descriptions and
+/// tooltips.
+pub mod text;
+
+#[cfg(test)]
+mod fixtures;
+
 #[cfg(any(feature = "sync", feature = "async-base"))]
 mod error;
 
 #[cfg(any(feature = "sync", feature = "async-base"))]
 pub use error::ClientError;
 
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the rules deciding how long cached responses are reused for.
+pub mod cache_policy;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the rules deciding when failed requests are retried.
+pub mod retry;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains functions for inspecting and cleaning up a cache directory.
+pub mod cache;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the report returned when fetching many resources at once.
+pub mod batch;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the plans and reports for warming the cache with a whole patch.
+pub mod prefetch;
+
 #[cfg(feature = "sync")]
 /// Contains the local file caching middleware used for `ureq`.
 pub mod cache_middleware;
 
+#[cfg(feature = "async-base")]
+/// Contains the local file caching middleware used for `reqwest`.
+pub mod async_cache_middleware;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the endpoints describing every piece of data the clients return.
+pub mod endpoint;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the report of fields the models don't know about yet.
+pub mod drift;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+mod memo;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+/// Contains the data sources a client can read from instead of the network.
+pub mod source;
+
 #[cfg(feature = "sync")]
 /// Contains the sync version of the client API.
 pub mod client;
@@ -0,0 +1,135 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::cache_policy::{CachePolicy, CacheRule};
+use crate::source::Resource;
+
+type Key = (Resource, TypeId);
+
+/// Keeps already parsed data in memory, so requesting the same resource again
+/// skips reading and deserializing it. Holds at most `max_entries` resources,
+/// however large each one is, and evicts the least recently used one to make
+/// room for another.
+///
+/// Only ddragon data and images pinned to a version are kept, and only while
+/// the cache policy treats their path as immutable. The lists of versions and
+/// languages, and cdragon data, can change within a patch, so they are always
+/// fetched again.
+pub(crate) struct Memo {
+    max_entries: usize,
+    policy: CachePolicy,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    entries: HashMap<Key, (Arc<dyn Any + Send + Sync>, u64)>,
+    clock: u64,
+}
+
+impl Memo {
+    pub(crate) fn new(max_entries: usize, policy: CachePolicy) -> Self {
+        Self { max_entries, policy, state: Mutex::default() }
+    }
+
+    fn holds(&self, resource: &Resource) -> bool {
+        matches!(resource, Resource::Data { .. } | Resource::Image { .. })
+            && self.policy.rule_for(&format!("/{}", resource.path())) == CacheRule::Immutable
+    }
+
+    /// Returns the data parsed by endpoint `E` for `resource`, if it is still
+    /// held.
+    pub(crate) fn get<E: 'static, T: Any + Send + Sync>(
+        &self,
+        resource: &Resource,
+    ) -> Option<Arc<T>> {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+
+        let (data, last_used) = state.entries.get_mut(&(resource.clone(), TypeId::of::<E>()))?;
+        *last_used = clock;
+        data.clone().downcast().ok()
+    }
+
+    /// Holds the data parsed by endpoint `E` for `resource`, evicting the
+    /// least recently used entry if there's no room for it.
+    pub(crate) fn insert<E: 'static, T: Any + Send + Sync>(
+        &self,
+        resource: Resource,
+        data: Arc<T>,
+    ) {
+        if self.max_entries == 0 || !self.holds(&resource) {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+
+        let key = (resource, TypeId::of::<E>());
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.max_entries {
+            let oldest = state.entries.iter().min_by_key(|(_, (_, used))| *used);
+            if let Some(oldest) = oldest.map(|(key, _)| key.clone()) {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.entries.insert(key, (data, clock));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Locale;
+
+    fn item_data(version: &str) -> Resource {
+        Resource::Data {
+            version: version.to_owned(),
+            locale: Locale::EnUs,
+            path: "item.json".to_owned(),
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let memo = Memo::new(2, CachePolicy::default());
+        memo.insert::<(), _>(item_data("1.1.1"), Arc::new(1));
+        memo.insert::<(), _>(item_data("1.2.1"), Arc::new(2));
+        assert_eq!(memo.get::<(), i32>(&item_data("1.1.1")).as_deref(), Some(&1));
+
+        memo.insert::<(), _>(item_data("1.3.1"), Arc::new(3));
+        assert_eq!(memo.get::<(), i32>(&item_data("1.1.1")).as_deref(), Some(&1));
+        assert_eq!(memo.get::<(), i32>(&item_data("1.2.1")), None);
+        assert_eq!(memo.get::<(), i32>(&item_data("1.3.1")).as_deref(), Some(&3));
+    }
+
+    #[test]
+    fn keyed_by_endpoint_and_skips_unversioned() {
+        let memo = Memo::new(4, CachePolicy::default());
+        memo.insert::<(), _>(item_data("1.1.1"), Arc::new(1));
+        memo.insert::<(), _>(Resource::Versions, Arc::new(2));
+
+        assert_eq!(memo.get::<u8, i32>(&item_data("1.1.1")), None);
+        assert_eq!(memo.get::<(), i32>(&Resource::Versions), None);
+    }
+
+    #[test]
+    fn skips_resources_without_an_immutable_rule() {
+        let policy = CachePolicy::default().rule("/cdn/1.2.1/*", CacheRule::Revalidate);
+        let memo = Memo::new(4, policy);
+        memo.insert::<(), _>(item_data("1.2.1"), Arc::new(1));
+        assert_eq!(memo.get::<(), i32>(&item_data("1.2.1")), None);
+
+        #[cfg(feature = "cdragon")]
+        {
+            let arena =
+                Resource::CDragon { patch: "1.1".to_owned(), path: "arena.json".to_owned() };
+            memo.insert::<(), _>(arena.clone(), Arc::new(2));
+            assert_eq!(memo.get::<(), i32>(&arena), None);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use super::{
+    champion::{ChampionStats, StatsAtLevel},
+    items::{Item, ItemStat},
+};
+
+/// The most items a champion can hold at once.
+pub const MAX_ITEMS: usize = 6;
+
+/// The combined stats of up to [MAX_ITEMS] items.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use ddragon::models::{build::Build, champion::ChampionStats, items::ItemStat};
+///
+/// let stats = HashMap::from([(ItemStat::FlatPhysicalDamage, 10.0)]);
+/// let build = Build::from_stats([&stats, &stats]).unwrap();
+/// assert_eq!(build.stat(&ItemStat::FlatPhysicalDamage), 20.0);
+///
+/// let champion = ChampionStats { attack_damage: 60.0, ..Default::default() };
+/// assert_eq!(build.stats_at_level(&champion, 1).attack_damage, 80.0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Build {
+    stats: HashMap<ItemStat, f64>,
+}
+
+impl Build {
+    /// Sums the stats of `items`, failing if there are more than [MAX_ITEMS].
+    pub fn new<'a>(items: impl IntoIterator<Item = &'a Item>) -> Result<Self, TooManyItems> {
+        Self::from_stats(items.into_iter().map(|item| &item.stats))
+    }
+
+    /// Sums the stats of up to [MAX_ITEMS] items, given as their stat maps.
+    pub fn from_stats<'a>(
+        items: impl IntoIterator<Item = &'a HashMap<ItemStat, f64>>,
+    ) -> Result<Self, TooManyItems> {
+        let mut build = Self::default();
+        let mut count = 0;
+        for stats in items {
+            count += 1;
+            for (stat, value) in stats {
+                *build.stats.entry(stat.clone()).or_default() += value;
+            }
+        }
+
+        if count > MAX_ITEMS { Err(TooManyItems(count)) } else { Ok(build) }
+    }
+
+    /// Every stat granted by the build.
+    pub fn stats(&self) -> &HashMap<ItemStat, f64> {
+        &self.stats
+    }
+
+    /// The total of a single stat, or `0.0` if no item grants it.
+    pub fn stat(&self, stat: &ItemStat) -> f64 {
+        self.stats.get(stat).copied().unwrap_or_default()
+    }
+
+    /// A champion's stats at `level` while holding the build. Flat bonuses
+    /// are added first, and percentage bonuses are then applied to the total.
+    /// Bonus attack speed scales with the champion's base attack speed, the
+    /// same as in [ChampionStats::stats_at_level]. See
+    /// [Build::stats_at_level_with_ratio] for champions with a different
+    /// attack speed ratio.
+    ///
+    /// Stats that don't affect any of these, like gold or experience bonuses,
+    /// are listed by [Build::unapplied_stats].
+    pub fn stats_at_level(&self, champion: &ChampionStats, level: u32) -> StatsAtLevel {
+        self.stats_at_level_with_ratio(champion, level, champion.attack_speed)
+    }
+
+    /// A champion's stats at `level` while holding the build, as with
+    /// [Build::stats_at_level], but with bonus attack speed from both levels
+    /// and items scaling with `attack_speed_ratio`.
+    pub fn stats_at_level_with_ratio(
+        &self,
+        champion: &ChampionStats,
+        level: u32,
+        attack_speed_ratio: f64,
+    ) -> StatsAtLevel {
+        let base = champion.stats_at_level_with_ratio(level, attack_speed_ratio);
+        let per_level = f64::from(level.max(1));
+        let total = |base: f64, flat: ItemStat, flat_per_level: ItemStat, percent: ItemStat| {
+            (base + self.stat(&flat) + self.stat(&flat_per_level) * per_level)
+                * (1.0 + self.stat(&percent))
+        };
+        let sum = |stat: ItemStat, stat_per_level: ItemStat| {
+            self.stat(&stat) + self.stat(&stat_per_level) * per_level
+        };
+
+        use ItemStat::*;
+        StatsAtLevel {
+            hp: total(base.hp, FlatHp, FlatHpPerLevel, PercentHp),
+            mp: total(base.mp, FlatMp, FlatMpPerLevel, PercentMp),
+            move_speed: total(
+                base.move_speed,
+                FlatMovementSpeed,
+                FlatMovementSpeedPerLevel,
+                PercentMovementSpeed,
+            ),
+            armor: total(base.armor, FlatArmor, FlatArmorPerLevel, PercentArmor),
+            spell_block: total(
+                base.spell_block,
+                FlatSpellBlock,
+                FlatSpellBlockPerLevel,
+                PercentSpellBlock,
+            ),
+            attack_range: base.attack_range,
+            hp_regen: total(base.hp_regen, FlatHpRegen, FlatHpRegenPerLevel, PercentHpRegen),
+            mp_regen: total(base.mp_regen, FlatMpRegen, FlatMpRegenPerLevel, PercentMpRegen),
+            crit: base.crit
+                + self.stat(&FlatCritChance)
+                + self.stat(&FlatCritChancePerLevel) * per_level,
+            attack_damage: total(
+                base.attack_damage,
+                FlatPhysicalDamage,
+                FlatPhysicalDamagePerLevel,
+                PercentPhysicalDamage,
+            ),
+            attack_speed: base.attack_speed
+                + attack_speed_ratio * sum(PercentAttackSpeed, PercentAttackSpeedPerLevel),
+            ability_power: total(
+                base.ability_power,
+                FlatMagicDamage,
+                FlatMagicDamagePerLevel,
+                PercentMagicDamage,
+            ),
+            crit_damage: sum(FlatCritDamage, FlatCritDamagePerLevel),
+            armor_penetration: sum(FlatArmorPenetration, FlatArmorPenetrationPerLevel),
+            percent_armor_penetration: sum(
+                PercentArmorPenetration,
+                PercentArmorPenetrationPerLevel,
+            ),
+            magic_penetration: sum(FlatMagicPenetration, FlatMagicPenetrationPerLevel),
+            percent_magic_penetration: sum(
+                PercentMagicPenetration,
+                PercentMagicPenetrationPerLevel,
+            ),
+            life_steal: self.stat(&PercentLifeSteal),
+            spell_vamp: self.stat(&PercentSpellVamp),
+            cooldown_reduction: sum(PercentCooldown, PercentCooldownPerLevel),
+        }
+    }
+
+    /// The stats granted by the build that [Build::stats_at_level] doesn't
+    /// account for, e.g. [ItemStat::FlatGoldPer10] or stats ddragon added
+    /// after this crate was released.
+    pub fn unapplied_stats(&self) -> HashMap<ItemStat, f64> {
+        let mut stats = self.stats.clone();
+        stats.retain(|stat, _| !is_applied(stat));
+        stats
+    }
+}
+
+/// Whether [Build::stats_at_level] accounts for a stat.
+fn is_applied(stat: &ItemStat) -> bool {
+    use ItemStat::*;
+    matches!(
+        stat,
+        FlatHp
+            | FlatHpPerLevel
+            | PercentHp
+            | FlatMp
+            | FlatMpPerLevel
+            | PercentMp
+            | FlatMovementSpeed
+            | FlatMovementSpeedPerLevel
+            | PercentMovementSpeed
+            | FlatArmor
+            | FlatArmorPerLevel
+            | PercentArmor
+            | FlatSpellBlock
+            | FlatSpellBlockPerLevel
+            | PercentSpellBlock
+            | FlatHpRegen
+            | FlatHpRegenPerLevel
+            | PercentHpRegen
+            | FlatMpRegen
+            | FlatMpRegenPerLevel
+            | PercentMpRegen
+            | FlatCritChance
+            | FlatCritChancePerLevel
+            | FlatPhysicalDamage
+            | FlatPhysicalDamagePerLevel
+            | PercentPhysicalDamage
+            | PercentAttackSpeed
+            | PercentAttackSpeedPerLevel
+            | FlatMagicDamage
+            | FlatMagicDamagePerLevel
+            | PercentMagicDamage
+            | FlatCritDamage
+            | FlatCritDamagePerLevel
+            | FlatArmorPenetration
+            | FlatArmorPenetrationPerLevel
+            | PercentArmorPenetration
+            | PercentArmorPenetrationPerLevel
+            | FlatMagicPenetration
+            | FlatMagicPenetrationPerLevel
+            | PercentMagicPenetration
+            | PercentMagicPenetrationPerLevel
+            | PercentLifeSteal
+            | PercentSpellVamp
+            | PercentCooldown
+            | PercentCooldownPerLevel
+    )
+}
+
+/// Returned when a [Build] is given more than [MAX_ITEMS] items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyItems(pub usize);
+
+impl fmt::Display for TooManyItems {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a build holds at most {MAX_ITEMS} items, got {}", self.0)
+    }
+}
+
+impl Error for TooManyItems {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(stats: &[(ItemStat, f64)]) -> HashMap<ItemStat, f64> {
+        stats.iter().cloned().collect()
+    }
+
+    #[test]
+    fn sums_stats_across_items() {
+        let boots = stats(&[(ItemStat::FlatMovementSpeed, 25.0)]);
+        let dagger = stats(&[(ItemStat::PercentAttackSpeed, 0.12)]);
+        let build = Build::from_stats([&boots, &dagger, &dagger]).unwrap();
+
+        assert_eq!(build.stat(&ItemStat::FlatMovementSpeed), 25.0);
+        assert_eq!(build.stat(&ItemStat::PercentAttackSpeed), 0.24);
+        assert_eq!(build.stat(&ItemStat::FlatArmor), 0.0);
+    }
+
+    #[test]
+    fn rejects_more_than_six_items() {
+        let empty = HashMap::new();
+        assert_eq!(Build::from_stats([&empty; 7]), Err(TooManyItems(7)));
+        assert!(Build::from_stats([&empty; 6]).is_ok());
+    }
+
+    #[test]
+    fn applies_bonuses_to_leveled_stats() {
+        let champion = ChampionStats {
+            hp: 600.0,
+            hp_per_level: 100.0,
+            move_speed: 330.0,
+            attack_speed: 0.625,
+            ..Default::default()
+        };
+        let build = Build::from_stats([&stats(&[
+            (ItemStat::FlatHp, 300.0),
+            (ItemStat::PercentHp, 0.1),
+            (ItemStat::FlatMovementSpeed, 45.0),
+            (ItemStat::PercentAttackSpeed, 0.5),
+        ])])
+        .unwrap();
+
+        let at_18 = build.stats_at_level(&champion, 18);
+        assert!((at_18.hp - (600.0 + 1700.0 + 300.0) * 1.1).abs() < 1e-9);
+        assert_eq!(at_18.move_speed, 375.0);
+        assert_eq!(at_18.attack_speed, 0.625 * 1.5);
+    }
+
+    #[test]
+    fn scales_bonus_attack_speed_with_the_ratio() {
+        let champion = ChampionStats { attack_speed: 0.658, ..Default::default() };
+        let build = Build::from_stats([&stats(&[(ItemStat::PercentAttackSpeed, 0.5)])]).unwrap();
+
+        let at_1 = build.stats_at_level_with_ratio(&champion, 1, 0.625);
+        assert!((at_1.attack_speed - (0.658 + 0.625 * 0.5)).abs() < 1e-9);
+        assert_eq!(build.stats_at_level(&champion, 1).attack_speed, 0.658 * 1.5);
+    }
+
+    #[test]
+    fn applies_ability_power_and_lists_the_rest() {
+        let build = Build::from_stats([&stats(&[
+            (ItemStat::FlatMagicDamage, 80.0),
+            (ItemStat::FlatMagicPenetration, 15.0),
+            (ItemStat::FlatGoldPer10, 3.0),
+        ])])
+        .unwrap();
+
+        let at_1 = build.stats_at_level(&ChampionStats::default(), 1);
+        assert_eq!(at_1.ability_power, 80.0);
+        assert_eq!(at_1.magic_penetration, 15.0);
+        assert_eq!(build.unapplied_stats(), stats(&[(ItemStat::FlatGoldPer10, 3.0)]));
+    }
+}
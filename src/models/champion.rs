@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::shared::{Image, has_image};
+use super::{
+    shared::{Image, has_image},
+    tooltip::{RenderedTooltip, SpellValues},
+};
 
-#[derive(Serialize, Deserialize)]
-pub(crate) struct ChampionWrapper {
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChampionWrapper {
     pub format: String,
     pub version: String,
     pub data: HashMap<String, Champion>,
@@ -25,7 +28,7 @@ pub struct Champion {
     pub tags: Vec<Tag>,
     pub partype: String,
     pub info: Info,
-    pub stats: HashMap<String, f64>,
+    pub stats: ChampionStats,
     pub spells: Vec<Spell>,
     pub passive: Passive,
 }
@@ -40,6 +43,153 @@ pub struct Info {
     pub difficulty: i64,
 }
 
+/// A champion's base stats, and how much each grows per level. A stat missing
+/// from the data reads as `0.0`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ChampionStats {
+    pub hp: f64,
+    #[serde(rename = "hpperlevel")]
+    pub hp_per_level: f64,
+    pub mp: f64,
+    #[serde(rename = "mpperlevel")]
+    pub mp_per_level: f64,
+    #[serde(rename = "movespeed")]
+    pub move_speed: f64,
+    pub armor: f64,
+    #[serde(rename = "armorperlevel")]
+    pub armor_per_level: f64,
+    #[serde(rename = "spellblock")]
+    pub spell_block: f64,
+    #[serde(rename = "spellblockperlevel")]
+    pub spell_block_per_level: f64,
+    #[serde(rename = "attackrange")]
+    pub attack_range: f64,
+    #[serde(rename = "hpregen")]
+    pub hp_regen: f64,
+    #[serde(rename = "hpregenperlevel")]
+    pub hp_regen_per_level: f64,
+    #[serde(rename = "mpregen")]
+    pub mp_regen: f64,
+    #[serde(rename = "mpregenperlevel")]
+    pub mp_regen_per_level: f64,
+    pub crit: f64,
+    #[serde(rename = "critperlevel")]
+    pub crit_per_level: f64,
+    #[serde(rename = "attackdamage")]
+    pub attack_damage: f64,
+    #[serde(rename = "attackdamageperlevel")]
+    pub attack_damage_per_level: f64,
+    /// The bonus attack speed gained per level, as a percentage.
+    #[serde(rename = "attackspeedperlevel")]
+    pub attack_speed_per_level: f64,
+    #[serde(rename = "attackspeed")]
+    pub attack_speed: f64,
+    /// Any stats without a field of their own, by their ddragon name. As these
+    /// are kept, they aren't listed in a client's drift report.
+    #[serde(flatten)]
+    pub other: HashMap<String, f64>,
+}
+
+/// A champion's stats at a specific level, from [ChampionStats::stats_at_level].
+///
+/// Champions have none of the offensive stats after `attack_speed` on their
+/// own, so they are only ever non-zero when granted by a
+/// [Build](super::build::Build).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct StatsAtLevel {
+    pub hp: f64,
+    pub mp: f64,
+    pub move_speed: f64,
+    pub armor: f64,
+    pub spell_block: f64,
+    pub attack_range: f64,
+    pub hp_regen: f64,
+    pub mp_regen: f64,
+    pub crit: f64,
+    pub attack_damage: f64,
+    pub attack_speed: f64,
+    pub ability_power: f64,
+    /// Bonus critical strike damage, as a fraction of the base.
+    pub crit_damage: f64,
+    pub armor_penetration: f64,
+    pub percent_armor_penetration: f64,
+    pub magic_penetration: f64,
+    pub percent_magic_penetration: f64,
+    pub life_steal: f64,
+    pub spell_vamp: f64,
+    /// Cooldown reduction, with the sign ddragon uses for it.
+    pub cooldown_reduction: f64,
+}
+
+impl ChampionStats {
+    /// The champion's stats at `level` (counting from 1), using the same
+    /// growth curve as the game, where each level grants slightly more than
+    /// the last and level 18 grants exactly 17 times the per level growth.
+    ///
+    /// Bonus attack speed scales with the champion's attack speed ratio,
+    /// which ddragon doesn't publish. This assumes the ratio is the same as
+    /// the base attack speed, which holds for most champions. See
+    /// [ChampionStats::stats_at_level_with_ratio] for the others.
+    pub fn stats_at_level(&self, level: u32) -> StatsAtLevel {
+        self.stats_at_level_with_ratio(level, self.attack_speed)
+    }
+
+    /// The champion's stats at `level`, as with [ChampionStats::stats_at_level],
+    /// but with bonus attack speed scaling with `attack_speed_ratio`.
+    pub fn stats_at_level_with_ratio(&self, level: u32, attack_speed_ratio: f64) -> StatsAtLevel {
+        let grow = |base: f64, per_level: f64| base + growth(per_level, level);
+        StatsAtLevel {
+            hp: grow(self.hp, self.hp_per_level),
+            mp: grow(self.mp, self.mp_per_level),
+            move_speed: self.move_speed,
+            armor: grow(self.armor, self.armor_per_level),
+            spell_block: grow(self.spell_block, self.spell_block_per_level),
+            attack_range: self.attack_range,
+            hp_regen: grow(self.hp_regen, self.hp_regen_per_level),
+            mp_regen: grow(self.mp_regen, self.mp_regen_per_level),
+            crit: grow(self.crit, self.crit_per_level),
+            attack_damage: grow(self.attack_damage, self.attack_damage_per_level),
+            attack_speed: self.attack_speed
+                + attack_speed_ratio * growth(self.attack_speed_per_level, level) / 100.0,
+            ..Default::default()
+        }
+    }
+
+    /// Every stat with a field of its own, by its ddragon name (e.x.
+    /// `hpperlevel`). See [ChampionStats::other] for the rest.
+    pub fn fields(&self) -> [(&'static str, f64); 20] {
+        [
+            ("hp", self.hp),
+            ("hpperlevel", self.hp_per_level),
+            ("mp", self.mp),
+            ("mpperlevel", self.mp_per_level),
+            ("movespeed", self.move_speed),
+            ("armor", self.armor),
+            ("armorperlevel", self.armor_per_level),
+            ("spellblock", self.spell_block),
+            ("spellblockperlevel", self.spell_block_per_level),
+            ("attackrange", self.attack_range),
+            ("hpregen", self.hp_regen),
+            ("hpregenperlevel", self.hp_regen_per_level),
+            ("mpregen", self.mp_regen),
+            ("mpregenperlevel", self.mp_regen_per_level),
+            ("crit", self.crit),
+            ("critperlevel", self.crit_per_level),
+            ("attackdamage", self.attack_damage),
+            ("attackdamageperlevel", self.attack_damage_per_level),
+            ("attackspeedperlevel", self.attack_speed_per_level),
+            ("attackspeed", self.attack_speed),
+        ]
+    }
+}
+
+/// The total growth of a stat from level 1 to `level`.
+fn growth(per_level: f64, level: u32) -> f64 {
+    let levels = f64::from(level.max(1) - 1);
+    per_level * levels * (0.7025 + 0.0175 * levels)
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Passive {
     pub name: String,
@@ -86,6 +236,29 @@ pub struct Spell {
 
 has_image!(Spell);
 
+impl Spell {
+    /// Renders the tooltip at `rank` (counting from 1), filling in the
+    /// placeholders ddragon still publishes values for. Without a rank, values
+    /// are shown for every rank, e.x. `60/90/120`.
+    pub fn render_tooltip(&self, rank: Option<usize>) -> RenderedTooltip {
+        SpellValues {
+            tooltip: &self.tooltip,
+            effect: &self.effect,
+            effect_burn: &self.effect_burn,
+            cooldown: &self.cooldown,
+            cooldown_burn: &self.cooldown_burn,
+            cost: &self.cost,
+            cost_burn: &self.cost_burn,
+            range: &self.range,
+            range_burn: &self.range_burn,
+            maxammo: &self.maxammo,
+            max_rank: usize::try_from(self.maxrank).unwrap_or_default(),
+            leveltip: self.leveltip.as_ref(),
+        }
+        .render(rank)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct LevelTip {
     pub label: Vec<String>,
@@ -101,3 +274,63 @@ pub enum Tag {
     Support,
     Tank,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> ChampionStats {
+        ChampionStats {
+            hp: 590.0,
+            hp_per_level: 104.0,
+            armor: 21.0,
+            armor_per_level: 4.2,
+            attack_damage: 53.0,
+            attack_damage_per_level: 3.0,
+            attack_speed_per_level: 2.2,
+            attack_speed: 0.668,
+            move_speed: 330.0,
+            ..Default::default()
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn level_one_has_base_stats() {
+        let level_1 = stats().stats_at_level(1);
+        assert_close(level_1.hp, 590.0);
+        assert_close(level_1.attack_speed, 0.668);
+        assert_eq!(stats().stats_at_level(0), level_1);
+    }
+
+    #[test]
+    fn growth_follows_the_level_curve() {
+        let level_2 = stats().stats_at_level(2);
+        let level_18 = stats().stats_at_level(18);
+
+        assert_close(level_2.hp, 590.0 + 104.0 * 0.72);
+        assert_close(level_18.hp, 590.0 + 104.0 * 17.0);
+        assert_close(level_18.armor, 21.0 + 4.2 * 17.0);
+        assert_close(level_18.move_speed, 330.0);
+    }
+
+    #[test]
+    fn tolerates_missing_and_unknown_stats() {
+        let stats: ChampionStats = serde_json::from_str(r#"{"hp": 590, "tenacity": 5}"#).unwrap();
+        assert_eq!(stats.hp, 590.0);
+        assert_eq!(stats.crit_per_level, 0.0);
+        assert_eq!(stats.other, HashMap::from([("tenacity".to_owned(), 5.0)]));
+    }
+
+    #[test]
+    fn attack_speed_scales_with_ratio() {
+        assert_close(stats().stats_at_level(18).attack_speed, 0.668 + 0.668 * 0.022 * 17.0);
+        assert_close(
+            stats().stats_at_level_with_ratio(18, 0.625).attack_speed,
+            0.668 + 0.625 * 0.022 * 17.0,
+        );
+    }
+}
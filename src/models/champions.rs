@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::{
-    champion::{Info, Tag},
+    champion::{ChampionStats, Info, Tag},
     shared::{Image, has_image},
 };
 
@@ -25,7 +25,7 @@ pub struct ChampionShort {
     pub image: Image,
     pub tags: Vec<Tag>,
     pub partype: String,
-    pub stats: HashMap<String, f64>,
+    pub stats: ChampionStats,
 }
 
 has_image!(ChampionShort);
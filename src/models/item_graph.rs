@@ -0,0 +1,237 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::items::{Item, Items};
+
+/// The recipes connecting items, for walking from an item to its components
+/// and back. Built from [Items], and optionally narrowed down to the items
+/// available on a map or in the shop.
+///
+/// Components that aren't part of the graph, e.g. because they were filtered
+/// out, are skipped wherever recipes are walked, as are components that would
+/// make a recipe contain itself. The exception is
+/// [remaining_cost](ItemGraph::remaining_cost), which can't price a recipe
+/// without them.
+///
+/// ```
+/// use ddragon::models::{Items, item_graph::ItemGraph};
+///
+/// fn infinity_edge_cost(items: &Items, owned: &[&str]) -> Option<i64> {
+///     let summoners_rift = ItemGraph::new(items).on_map("11").in_store();
+///     summoners_rift.remaining_cost("3031", owned)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ItemGraph<'a> {
+    items: HashMap<&'a str, &'a Item>,
+}
+
+impl<'a> ItemGraph<'a> {
+    /// Creates a graph of every item.
+    pub fn new(items: &'a Items) -> Self {
+        Self { items: items.data.iter().map(|(id, item)| (id.as_str(), item)).collect() }
+    }
+
+    /// Keeps only the items for which `keep` returns true.
+    pub fn filter(mut self, keep: impl Fn(&Item) -> bool) -> Self {
+        self.items.retain(|_, item| keep(item));
+        self
+    }
+
+    /// Keeps only the items available on a map, by its ID (e.x. `11` for
+    /// Summoner's Rift).
+    pub fn on_map(self, map_id: &str) -> Self {
+        self.filter(|item| item.maps.get(map_id).copied().unwrap_or(false))
+    }
+
+    /// Keeps only the items that can be bought in the shop, dropping those
+    /// that are not in store or hidden from everyone.
+    pub fn in_store(self) -> Self {
+        self.filter(|item| item.in_store != Some(false) && item.hide_from_all != Some(true))
+    }
+
+    /// Returns an item in the graph by its ID.
+    pub fn item(&self, id: &str) -> Option<&'a Item> {
+        self.items.get(id).copied()
+    }
+
+    /// The IDs of every item in the graph, sorted.
+    pub fn ids(&self) -> Vec<&'a str> {
+        let mut ids: Vec<_> = self.items.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// The components an item is directly built from. A component appears
+    /// once for every time the recipe uses it.
+    pub fn components(&self, id: &str) -> Vec<&'a str> {
+        let from = self.item(id).and_then(|item| item.from.as_ref());
+        from.into_iter()
+            .flatten()
+            .filter_map(|component| self.items.get_key_value(component.as_str()))
+            .map(|(component, _)| *component)
+            .collect()
+    }
+
+    /// The order to buy everything in an item's recipe, from its most basic
+    /// components up to the item itself, which comes last. Empty if the item
+    /// isn't in the graph.
+    pub fn build_path(&self, id: &str) -> Vec<&'a str> {
+        let mut path = vec![];
+        if let Some((id, _)) = self.items.get_key_value(id) {
+            self.walk_path(id, &mut path, &mut vec![]);
+        }
+        path
+    }
+
+    fn walk_path(&self, id: &'a str, path: &mut Vec<&'a str>, walking: &mut Vec<&'a str>) {
+        walking.push(id);
+        for component in self.components(id) {
+            if !walking.contains(&component) {
+                self.walk_path(component, path, walking);
+            }
+        }
+        walking.pop();
+        path.push(id);
+    }
+
+    /// Every item that uses an item anywhere in its recipe, sorted by ID.
+    pub fn builds_into(&self, id: &str) -> Vec<&'a str> {
+        let mut found = BTreeSet::new();
+        let mut pending = vec![id];
+        while let Some(current) = pending.pop() {
+            for (&parent, item) in &self.items {
+                let uses = item.from.iter().flatten().any(|component| component == current);
+                if uses && found.insert(parent) {
+                    pending.push(parent);
+                }
+            }
+        }
+        found.into_iter().collect()
+    }
+
+    /// How much gold is still needed to buy an item when already holding
+    /// `owned`, which are used up as components wherever the recipe allows.
+    /// Returns `None` if the item isn't in the graph, or if buying it needs a
+    /// component that isn't, as its price is unknown.
+    pub fn remaining_cost(&self, id: &str, owned: &[&str]) -> Option<i64> {
+        if owned.contains(&id) {
+            return self.item(id).map(|_| 0);
+        }
+        self.cost_with(id, &mut owned.to_vec(), &mut vec![])
+    }
+
+    fn cost_with(
+        &self,
+        id: &str,
+        owned: &mut Vec<&str>,
+        walking: &mut Vec<&'a str>,
+    ) -> Option<i64> {
+        let (id, item) = self.items.get_key_value(id)?;
+        let mut cost = item.gold.base;
+        walking.push(id);
+        for component in item.from.iter().flatten().map(String::as_str) {
+            if walking.contains(&component) {
+                continue;
+            }
+            match owned.iter().position(|owned| *owned == component) {
+                Some(held) => {
+                    owned.swap_remove(held);
+                }
+                None => cost += self.cost_with(component, owned, walking)?,
+            }
+        }
+        walking.pop();
+        Some(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn item(base: i64, from: &[&str], maps: &[&str], in_store: Option<bool>) -> Item {
+        Item {
+            maps: maps.iter().map(|m| (m.to_string(), true)).collect(),
+            in_store,
+            from: (!from.is_empty()).then(|| from.iter().map(|f| f.to_string()).collect()),
+            ..fixtures::item(base)
+        }
+    }
+
+    // A long sword (1036) and pickaxe (1037) build into a caulfield's
+    // (3133), which with another long sword builds into a black cleaver
+    // (3071).
+    fn items() -> Items {
+        fixtures::items([
+            ("1036", item(350, &[], &["11", "12"], None)),
+            ("1037", item(875, &[], &["11", "12"], None)),
+            ("3133", item(400, &["1036", "1037"], &["11", "12"], None)),
+            ("3071", item(1000, &["3133", "1036"], &["11"], None)),
+            ("9999", item(0, &["1036"], &["11"], Some(false))),
+        ])
+    }
+
+    #[test]
+    fn walks_components_and_build_paths() {
+        let items = items();
+        let graph = ItemGraph::new(&items);
+
+        assert_eq!(graph.components("3071"), vec!["3133", "1036"]);
+        assert_eq!(graph.build_path("3071"), vec!["1036", "1037", "3133", "1036", "3071"]);
+        assert!(graph.build_path("0000").is_empty());
+    }
+
+    #[test]
+    fn finds_everything_a_component_builds_into() {
+        let items = items();
+        let graph = ItemGraph::new(&items);
+
+        assert_eq!(graph.builds_into("1036"), vec!["3071", "3133", "9999"]);
+        assert_eq!(graph.builds_into("1037"), vec!["3071", "3133"]);
+        assert!(graph.builds_into("3071").is_empty());
+    }
+
+    #[test]
+    fn remaining_cost_uses_owned_components() {
+        let items = items();
+        let graph = ItemGraph::new(&items);
+
+        assert_eq!(graph.remaining_cost("3071", &[]), Some(2975));
+        assert_eq!(graph.remaining_cost("3071", &["1036"]), Some(2625));
+        assert_eq!(graph.remaining_cost("3071", &["3133", "1036"]), Some(1000));
+        assert_eq!(graph.remaining_cost("3071", &["3071"]), Some(0));
+        assert_eq!(graph.remaining_cost("0000", &[]), None);
+    }
+
+    #[test]
+    fn remaining_cost_needs_missing_components_owned() {
+        let items = items();
+        let graph = ItemGraph::new(&items).filter(|item| item.gold.base != 875);
+
+        assert_eq!(graph.remaining_cost("3071", &[]), None);
+        assert_eq!(graph.remaining_cost("3133", &["1037"]), Some(750));
+        assert_eq!(graph.build_path("3133"), vec!["1036", "3133"]);
+    }
+
+    #[test]
+    fn stops_at_cyclic_recipes() {
+        let mut items = items();
+        items.data.insert("1036".to_owned(), item(350, &["3071"], &["11"], None));
+        items.data.insert("1038".to_owned(), item(1300, &["1038"], &["11"], None));
+        let graph = ItemGraph::new(&items);
+
+        assert_eq!(graph.build_path("3071"), vec!["1036", "1037", "3133", "1036", "3071"]);
+        assert_eq!(graph.build_path("1038"), vec!["1038"]);
+        assert_eq!(graph.remaining_cost("3071", &[]), Some(2975));
+        assert_eq!(graph.remaining_cost("1038", &[]), Some(1300));
+    }
+
+    #[test]
+    fn filters_by_map_and_store() {
+        let items = items();
+
+        assert_eq!(ItemGraph::new(&items).on_map("12").ids(), vec!["1036", "1037", "3133"]);
+        assert_eq!(ItemGraph::new(&items).in_store().builds_into("1036"), vec!["3071", "3133"]);
+    }
+}
@@ -30,7 +30,7 @@ pub struct Item {
     pub gold: Gold,
     pub tags: Vec<String>,
     pub maps: HashMap<String, bool>,
-    pub stats: HashMap<String, f64>,
+    pub stats: HashMap<ItemStat, f64>,
     #[serde(rename = "inStore")]
     pub in_store: Option<bool>,
     pub from: Option<Vec<String>>,
@@ -52,6 +52,122 @@ pub struct Item {
 
 has_image!(Item);
 
+macro_rules! item_stats {
+    ($($variant:ident => $key:literal,)+) => {
+        /// A stat an item grants, e.x. `FlatHPPoolMod` for bonus health.
+        ///
+        /// Percentages are given as fractions, e.x. `0.25` for 25% attack
+        /// speed. Keys this crate doesn't know about are kept as
+        /// [ItemStat::Other].
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+        #[serde(from = "String", into = "String")]
+        pub enum ItemStat {
+            $(
+                #[doc = concat!(" `", $key, "`")]
+                $variant,
+            )+
+            /// Any key not listed above.
+            Other(String),
+        }
+
+        impl ItemStat {
+            /// The key ddragon uses for the stat.
+            pub fn key(&self) -> &str {
+                match self {
+                    $(Self::$variant => $key,)+
+                    Self::Other(key) => key,
+                }
+            }
+        }
+
+        impl From<String> for ItemStat {
+            fn from(key: String) -> Self {
+                match key.as_str() {
+                    $($key => Self::$variant,)+
+                    _ => Self::Other(key),
+                }
+            }
+        }
+    };
+}
+
+item_stats! {
+    FlatHp => "FlatHPPoolMod",
+    FlatHpPerLevel => "rFlatHPModPerLevel",
+    PercentHp => "PercentHPPoolMod",
+    FlatMp => "FlatMPPoolMod",
+    FlatMpPerLevel => "rFlatMPModPerLevel",
+    PercentMp => "PercentMPPoolMod",
+    FlatHpRegen => "FlatHPRegenMod",
+    FlatHpRegenPerLevel => "rFlatHPRegenModPerLevel",
+    PercentHpRegen => "PercentHPRegenMod",
+    FlatMpRegen => "FlatMPRegenMod",
+    FlatMpRegenPerLevel => "rFlatMPRegenModPerLevel",
+    PercentMpRegen => "PercentMPRegenMod",
+    FlatArmor => "FlatArmorMod",
+    FlatArmorPerLevel => "rFlatArmorModPerLevel",
+    PercentArmor => "PercentArmorMod",
+    FlatArmorPenetration => "rFlatArmorPenetrationMod",
+    FlatArmorPenetrationPerLevel => "rFlatArmorPenetrationModPerLevel",
+    PercentArmorPenetration => "rPercentArmorPenetrationMod",
+    PercentArmorPenetrationPerLevel => "rPercentArmorPenetrationModPerLevel",
+    FlatPhysicalDamage => "FlatPhysicalDamageMod",
+    FlatPhysicalDamagePerLevel => "rFlatPhysicalDamageModPerLevel",
+    PercentPhysicalDamage => "PercentPhysicalDamageMod",
+    FlatMagicDamage => "FlatMagicDamageMod",
+    FlatMagicDamagePerLevel => "rFlatMagicDamageModPerLevel",
+    PercentMagicDamage => "PercentMagicDamageMod",
+    FlatMagicPenetration => "rFlatMagicPenetrationMod",
+    FlatMagicPenetrationPerLevel => "rFlatMagicPenetrationModPerLevel",
+    PercentMagicPenetration => "rPercentMagicPenetrationMod",
+    PercentMagicPenetrationPerLevel => "rPercentMagicPenetrationModPerLevel",
+    FlatMovementSpeed => "FlatMovementSpeedMod",
+    FlatMovementSpeedPerLevel => "rFlatMovementSpeedModPerLevel",
+    PercentMovementSpeed => "PercentMovementSpeedMod",
+    PercentMovementSpeedPerLevel => "rPercentMovementSpeedModPerLevel",
+    FlatAttackSpeed => "FlatAttackSpeedMod",
+    PercentAttackSpeed => "PercentAttackSpeedMod",
+    PercentAttackSpeedPerLevel => "rPercentAttackSpeedModPerLevel",
+    FlatDodge => "rFlatDodgeMod",
+    FlatDodgePerLevel => "rFlatDodgeModPerLevel",
+    PercentDodge => "PercentDodgeMod",
+    FlatCritChance => "FlatCritChanceMod",
+    FlatCritChancePerLevel => "rFlatCritChanceModPerLevel",
+    PercentCritChance => "PercentCritChanceMod",
+    FlatCritDamage => "FlatCritDamageMod",
+    FlatCritDamagePerLevel => "rFlatCritDamageModPerLevel",
+    PercentCritDamage => "PercentCritDamageMod",
+    FlatBlock => "FlatBlockMod",
+    PercentBlock => "PercentBlockMod",
+    FlatSpellBlock => "FlatSpellBlockMod",
+    FlatSpellBlockPerLevel => "rFlatSpellBlockModPerLevel",
+    PercentSpellBlock => "PercentSpellBlockMod",
+    FlatExpBonus => "FlatEXPBonus",
+    PercentExpBonus => "PercentEXPBonus",
+    PercentCooldown => "rPercentCooldownMod",
+    PercentCooldownPerLevel => "rPercentCooldownModPerLevel",
+    FlatTimeDead => "rFlatTimeDeadMod",
+    FlatTimeDeadPerLevel => "rFlatTimeDeadModPerLevel",
+    PercentTimeDead => "rPercentTimeDeadMod",
+    PercentTimeDeadPerLevel => "rPercentTimeDeadModPerLevel",
+    FlatGoldPer10 => "rFlatGoldPer10Mod",
+    FlatEnergy => "FlatEnergyPoolMod",
+    FlatEnergyPerLevel => "rFlatEnergyModPerLevel",
+    FlatEnergyRegen => "FlatEnergyRegenMod",
+    FlatEnergyRegenPerLevel => "rFlatEnergyRegenModPerLevel",
+    PercentLifeSteal => "PercentLifeStealMod",
+    PercentSpellVamp => "PercentSpellVampMod",
+}
+
+impl From<ItemStat> for String {
+    fn from(stat: ItemStat) -> Self {
+        match stat {
+            ItemStat::Other(key) => key,
+            stat => stat.key().to_owned(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Effect {
     #[serde(rename = "Effect1Amount")]
@@ -104,3 +220,23 @@ pub struct Tree {
     pub header: String,
     pub tags: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_round_trip_known_and_unknown_keys() {
+        let json = r#"{"FlatHPPoolMod":150.0,"rFlatGoldPer10Mod":2.0,"NewMod":1.0}"#;
+        let stats: HashMap<ItemStat, f64> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats[&ItemStat::FlatHp], 150.0);
+        assert_eq!(stats[&ItemStat::FlatGoldPer10], 2.0);
+        assert_eq!(stats[&ItemStat::Other("NewMod".to_owned())], 1.0);
+        assert_eq!(
+            serde_json::from_str::<HashMap<ItemStat, f64>>(&serde_json::to_string(&stats).unwrap())
+                .unwrap(),
+            stats
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, fmt, str::FromStr};
+
+macro_rules! locales {
+    ($($variant:ident => $code:literal),+ $(,)?) => {
+        /// A language that ddragon publishes data for.
+        ///
+        /// Codes that aren't known to this crate yet are preserved as
+        /// [Locale::Other], so new languages can be used before they get a
+        /// dedicated variant.
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+        #[serde(from = "String", into = "String")]
+        pub enum Locale {
+            #[default]
+            EnUs,
+            $($variant,)+
+            Other(String),
+        }
+
+        impl Locale {
+            /// Every locale with a dedicated variant.
+            pub const KNOWN: &'static [Locale] = &[Locale::EnUs, $(Locale::$variant),+];
+
+            /// The code used in ddragon paths, e.g. `en_US`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Locale::EnUs => "en_US",
+                    $(Locale::$variant => $code,)+
+                    Locale::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for Locale {
+            fn from(code: &str) -> Self {
+                match code {
+                    "en_US" => Locale::EnUs,
+                    $($code => Locale::$variant,)+
+                    other => Locale::Other(other.to_owned()),
+                }
+            }
+        }
+    };
+}
+
+locales! {
+    ArAe => "ar_AE",
+    CsCz => "cs_CZ",
+    DeDe => "de_DE",
+    ElGr => "el_GR",
+    EnAu => "en_AU",
+    EnGb => "en_GB",
+    EnPh => "en_PH",
+    EnSg => "en_SG",
+    EsAr => "es_AR",
+    EsEs => "es_ES",
+    EsMx => "es_MX",
+    FrFr => "fr_FR",
+    HuHu => "hu_HU",
+    IdId => "id_ID",
+    ItIt => "it_IT",
+    JaJp => "ja_JP",
+    KoKr => "ko_KR",
+    PlPl => "pl_PL",
+    PtBr => "pt_BR",
+    RoRo => "ro_RO",
+    RuRu => "ru_RU",
+    ThTh => "th_TH",
+    TrTr => "tr_TR",
+    ViVn => "vi_VN",
+    ZhCn => "zh_CN",
+    ZhMy => "zh_MY",
+    ZhTw => "zh_TW",
+}
+
+impl From<String> for Locale {
+    fn from(code: String) -> Self {
+        match Locale::from(code.as_str()) {
+            Locale::Other(_) => Locale::Other(code),
+            known => known,
+        }
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        match locale {
+            Locale::Other(code) => code,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_codes_round_trip() {
+        for locale in Locale::KNOWN {
+            assert_eq!(&Locale::from(locale.as_str()), locale);
+        }
+    }
+
+    #[test]
+    fn unknown_codes_are_preserved() {
+        let locale: Locale = "xx_XX".parse().unwrap();
+        assert_eq!(locale, Locale::Other("xx_XX".to_owned()));
+        assert_eq!(locale.to_string(), "xx_XX");
+    }
+
+    #[test]
+    fn deserializes_from_code() {
+        let locales: Vec<Locale> = serde_json::from_str(r#"["ko_KR", "pt_BR"]"#).unwrap();
+        assert_eq!(locales, vec![Locale::KoKr, Locale::PtBr]);
+    }
+}
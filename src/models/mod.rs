@@ -1,10 +1,13 @@
+pub mod build;
 #[cfg(feature = "cdragon")]
 pub mod cdragon;
 pub mod challenges;
 pub mod champion;
 pub mod champions;
 pub mod champions_full;
+pub mod item_graph;
 pub mod items;
+pub mod locale;
 pub mod maps;
 pub mod mission_assets;
 pub mod profile_icons;
@@ -13,7 +16,9 @@ pub mod shared;
 pub mod spell_buffs;
 pub mod summoner_spells;
 pub mod tft;
+pub mod tooltip;
 pub mod translations;
+pub mod versions;
 
 #[cfg(feature = "cdragon")]
 pub use cdragon::augments::Augment;
@@ -22,6 +27,7 @@ pub use champion::Champion;
 pub use champions::Champions;
 pub use champions_full::ChampionsFull;
 pub use items::Items;
+pub use locale::Locale;
 pub use maps::Maps;
 pub use mission_assets::MissionAssets;
 pub use profile_icons::ProfileIcons;
@@ -29,3 +35,4 @@ pub use runes::Runes;
 pub use spell_buffs::SpellBuffs;
 pub use summoner_spells::SummonerSpells;
 pub use translations::Translations;
+pub use versions::{PatchVersion, Versions};
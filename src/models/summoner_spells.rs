@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::shared::{Image, has_image};
+use super::{
+    shared::{Image, has_image},
+    tooltip::{RenderedTooltip, SpellValues},
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct SummonerSpells {
@@ -40,3 +43,26 @@ pub struct SummonerSpell {
 }
 
 has_image!(SummonerSpell);
+
+impl SummonerSpell {
+    /// Renders the tooltip at `rank` (counting from 1), filling in the
+    /// placeholders ddragon still publishes values for. Without a rank, values
+    /// are shown for every rank, e.x. `60/90/120`.
+    pub fn render_tooltip(&self, rank: Option<usize>) -> RenderedTooltip {
+        SpellValues {
+            tooltip: &self.tooltip,
+            effect: &self.effect,
+            effect_burn: &self.effect_burn,
+            cooldown: &self.cooldown,
+            cooldown_burn: &self.cooldown_burn,
+            cost: &self.cost,
+            cost_burn: &self.cost_burn,
+            range: &self.range,
+            range_burn: &self.range_burn,
+            maxammo: &self.maxammo,
+            max_rank: usize::try_from(self.maxrank).unwrap_or_default(),
+            leveltip: None,
+        }
+        .render(rank)
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+
+use super::champion::LevelTip;
+
+/// A spell tooltip with its placeholders filled in, from
+/// [Spell::render_tooltip](super::champion::Spell::render_tooltip) or
+/// [SummonerSpell::render_tooltip](super::summoner_spells::SummonerSpell::render_tooltip).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderedTooltip {
+    /// The tooltip, with every placeholder that could be resolved replaced by
+    /// its value. Placeholders that couldn't be resolved are left as they are.
+    pub text: String,
+    /// The names of the placeholders that couldn't be resolved, e.x. `a1`, in
+    /// the order they first appear. ddragon no longer publishes the values of
+    /// many of them.
+    pub unresolved: Vec<String>,
+    /// What changes when the spell is ranked up, from the spell's level tip.
+    /// Only filled in when rendering a specific rank below the spell's last.
+    pub level_tip: Vec<LevelTipRow>,
+}
+
+/// A single value that changes when a spell is ranked up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LevelTipRow {
+    /// What the value is, e.x. `Damage` or `Cooldown`.
+    pub label: String,
+    /// The value at the rendered rank.
+    pub before: String,
+    /// The value at the next rank.
+    pub after: String,
+}
+
+/// Formats the row as `label: before → after`.
+impl fmt::Display for LevelTipRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} → {}", self.label, self.before, self.after)
+    }
+}
+
+/// The values a spell's tooltip placeholders can refer to.
+pub(super) struct SpellValues<'a> {
+    pub(super) tooltip: &'a str,
+    pub(super) effect: &'a [Option<Vec<f64>>],
+    pub(super) effect_burn: &'a [Option<String>],
+    pub(super) cooldown: &'a [f64],
+    pub(super) cooldown_burn: &'a str,
+    pub(super) cost: &'a [i64],
+    pub(super) cost_burn: &'a str,
+    pub(super) range: &'a [i64],
+    pub(super) range_burn: &'a str,
+    pub(super) maxammo: &'a str,
+    pub(super) max_rank: usize,
+    pub(super) leveltip: Option<&'a LevelTip>,
+}
+
+impl SpellValues<'_> {
+    /// Renders the tooltip at `rank` (counting from 1), or with the values
+    /// for every rank (e.x. `60/70/80`) if `rank` is `None`.
+    pub(super) fn render(&self, rank: Option<usize>) -> RenderedTooltip {
+        let mut unresolved = vec![];
+        let text = self.substitute(self.tooltip, rank, &mut unresolved);
+
+        let mut level_tip = vec![];
+        let next_rank = rank.filter(|rank| *rank < self.max_rank);
+        if let (Some(leveltip), Some(_)) = (self.leveltip, next_rank) {
+            for (label, effect) in leveltip.label.iter().zip(&leveltip.effect) {
+                let effect = self.substitute(effect, rank, &mut unresolved);
+                let (before, after) = effect.split_once("->").unwrap_or((&effect, ""));
+                level_tip.push(LevelTipRow {
+                    label: label.trim().to_owned(),
+                    before: before.trim().to_owned(),
+                    after: after.trim().to_owned(),
+                });
+            }
+        }
+
+        RenderedTooltip { text, unresolved, level_tip }
+    }
+
+    fn substitute(
+        &self,
+        template: &str,
+        rank: Option<usize>,
+        unresolved: &mut Vec<String>,
+    ) -> String {
+        let mut text = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            let Some(length) = rest[start..].find("}}") else {
+                break;
+            };
+            let placeholder = &rest[start..start + length + 2];
+            let name = placeholder[2..placeholder.len() - 2].trim();
+
+            text.push_str(&rest[..start]);
+            match self.resolve(name, rank) {
+                Some(value) => text.push_str(&value),
+                None => {
+                    text.push_str(placeholder);
+                    if !unresolved.iter().any(|n| n == name) {
+                        unresolved.push(name.to_owned());
+                    }
+                }
+            }
+            rest = &rest[start + length + 2..];
+        }
+        text.push_str(rest);
+        text
+    }
+
+    /// Resolves a placeholder, where an `NL` suffix (e.x. `e1NL`) refers to
+    /// the value at the next rank.
+    fn resolve(&self, name: &str, rank: Option<usize>) -> Option<String> {
+        let (name, rank) = match name.strip_suffix("NL") {
+            Some(name) => (name, Some(rank? + 1)),
+            None => (name, rank),
+        };
+
+        match name {
+            "cooldown" => at_rank(self.cooldown, self.cooldown_burn, rank),
+            "cost" => at_rank(self.cost, self.cost_burn, rank),
+            "range" => at_rank(self.range, self.range_burn, rank),
+            "maxammo" => Some(self.maxammo.to_owned()),
+            _ => {
+                let index: usize = name.strip_prefix('e')?.parse().ok()?;
+                match rank {
+                    Some(rank) => {
+                        let values = self.effect.get(index)?.as_ref()?;
+                        values.get(rank.checked_sub(1)?).map(|v| v.to_string())
+                    }
+                    None => self.effect_burn.get(index)?.clone(),
+                }
+            }
+        }
+    }
+}
+
+fn at_rank<T: ToString>(values: &[T], burn: &str, rank: Option<usize>) -> Option<String> {
+    match rank {
+        Some(rank) => values.get(rank.checked_sub(1)?).map(|v| v.to_string()),
+        None => Some(burn.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(tooltip: &str, leveltip: Option<&LevelTip>, rank: Option<usize>) -> RenderedTooltip {
+        let effect = [None, Some(vec![60.0, 90.0, 120.0]), Some(vec![0.5, 0.75, 1.0])];
+        let effect_burn = [None, Some("60/90/120".to_owned()), Some("0.5/0.75/1".to_owned())];
+        SpellValues {
+            tooltip,
+            effect: &effect,
+            effect_burn: &effect_burn,
+            cooldown: &[10.0, 9.5, 9.0],
+            cooldown_burn: "10/9.5/9",
+            cost: &[50, 60, 70],
+            cost_burn: "50/60/70",
+            range: &[600, 600, 600],
+            range_burn: "600",
+            maxammo: "-1",
+            max_rank: 3,
+            leveltip,
+        }
+        .render(rank)
+    }
+
+    #[test]
+    fn substitutes_values_at_rank() {
+        let tooltip = "Deals {{ e1 }} damage, slowing by {{e2}} for {{ cost }} mana.";
+        let rendered = render(tooltip, None, Some(2));
+
+        assert_eq!(rendered.text, "Deals 90 damage, slowing by 0.75 for 60 mana.");
+        assert!(rendered.unresolved.is_empty());
+    }
+
+    #[test]
+    fn substitutes_every_rank_without_a_rank() {
+        let rendered = render("Cooldown: {{ cooldown }}. Damage: {{ e1 }}.", None, None);
+        assert_eq!(rendered.text, "Cooldown: 10/9.5/9. Damage: 60/90/120.");
+    }
+
+    #[test]
+    fn reports_unresolved_placeholders() {
+        let tooltip = "Deals {{ e1 }} (+{{ a1 }}) damage, or {{ e7 }} with {{ a1 }}.";
+        let rendered = render(tooltip, None, Some(4));
+
+        assert_eq!(rendered.text, "Deals {{ e1 }} (+{{ a1 }}) damage, or {{ e7 }} with {{ a1 }}.");
+        assert_eq!(rendered.unresolved, vec!["e1", "a1", "e7"]);
+    }
+
+    #[test]
+    fn renders_level_tip_rows() {
+        let leveltip = LevelTip {
+            label: vec!["Damage".to_owned(), "Cooldown".to_owned()],
+            effect: vec![
+                "{{ e1 }} -> {{ e1NL }}".to_owned(),
+                "{{ cooldown }} -> {{ cooldownNL }}".to_owned(),
+            ],
+        };
+        let rendered = render("", Some(&leveltip), Some(1));
+
+        let rows: Vec<_> = rendered.level_tip.iter().map(|row| row.to_string()).collect();
+        assert_eq!(rows, vec!["Damage: 60 → 90", "Cooldown: 10 → 9.5"]);
+        assert!(render("", Some(&leveltip), None).level_tip.is_empty());
+    }
+
+    #[test]
+    fn omits_level_tip_at_max_rank() {
+        let leveltip = LevelTip {
+            label: vec!["Damage".to_owned()],
+            effect: vec!["{{ e1 }} -> {{ e1NL }}".to_owned()],
+        };
+        let rendered = render("", Some(&leveltip), Some(3));
+
+        assert!(rendered.level_tip.is_empty());
+        assert!(rendered.unresolved.is_empty());
+    }
+}
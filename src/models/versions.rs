@@ -0,0 +1,239 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{cmp::Ordering, error::Error, fmt, ops::Deref, str::FromStr};
+
+/// A single ddragon version, e.g. `14.3.1`.
+///
+/// The earliest versions in the list are named like `lolpatch_3.7`. These
+/// are parsed with a build number of `0` and are marked as `legacy`, so they
+/// order before any regular version of the same patch.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct PatchVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub build: u32,
+    pub legacy: bool,
+}
+
+impl PatchVersion {
+    pub fn new(major: u32, minor: u32, build: u32) -> Self {
+        Self { major, minor, build, legacy: false }
+    }
+
+    /// Whether this version belongs to the `major.minor` patch.
+    pub fn is_patch(&self, major: u32, minor: u32) -> bool {
+        self.major == major && self.minor == minor
+    }
+}
+
+impl Ord for PatchVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor)
+            .cmp(&(other.major, other.minor))
+            .then_with(|| other.legacy.cmp(&self.legacy))
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for PatchVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Trims a version down to its `major.minor` patch, e.g. both `14.3.1` and
+/// `14.3.558.2130` become `14.3`.
+pub fn patch_of(version: &str) -> &str {
+    match version.match_indices('.').nth(1) {
+        Some((index, _)) => &version[..index],
+        None => version,
+    }
+}
+
+/// Returned when a string is not a valid [PatchVersion].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePatchVersionError(String);
+
+impl fmt::Display for ParsePatchVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ddragon version: {:?}", self.0)
+    }
+}
+
+impl Error for ParsePatchVersionError {}
+
+impl FromStr for PatchVersion {
+    type Err = ParsePatchVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParsePatchVersionError(s.to_owned());
+        let (legacy, rest) = match s.strip_prefix("lolpatch_") {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let parts = rest
+            .split('.')
+            .map(|p| p.parse::<u32>().map_err(|_| error()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match (legacy, parts.as_slice()) {
+            (true, [major, minor]) => Ok(Self { major: *major, minor: *minor, build: 0, legacy }),
+            (false, [major, minor, build]) => Ok(Self::new(*major, *minor, *build)),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl TryFrom<String> for PatchVersion {
+    type Error = ParsePatchVersionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<PatchVersion> for String {
+    fn from(version: PatchVersion) -> Self {
+        version.to_string()
+    }
+}
+
+impl fmt::Display for PatchVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.legacy {
+            write!(f, "lolpatch_{}.{}", self.major, self.minor)
+        } else {
+            write!(f, "{}.{}.{}", self.major, self.minor, self.build)
+        }
+    }
+}
+
+/// Every version ddragon has published, in the order the API lists them
+/// (newest first). Entries that aren't a valid [PatchVersion] are skipped.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Versions(pub Vec<PatchVersion>);
+
+impl<'de> Deserialize<'de> for Versions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let versions = Vec::<String>::deserialize(deserializer)?;
+        Ok(Self(versions.iter().filter_map(|v| v.parse().ok()).collect()))
+    }
+}
+
+impl Versions {
+    /// The newest version in the list.
+    pub fn latest(&self) -> Option<&PatchVersion> {
+        self.0.iter().max()
+    }
+
+    /// The newest build of the `major.minor` patch, e.g. `14.3.1` for `14.3`.
+    pub fn latest_for(&self, major: u32, minor: u32) -> Option<&PatchVersion> {
+        self.0.iter().filter(|v| !v.legacy && v.is_patch(major, minor)).max()
+    }
+
+    /// The ddragon version for a game version as reported in match data, e.g.
+    /// `14.3.558.2130` resolves to the newest `14.3.x` version.
+    pub fn for_game_version(&self, game_version: &str) -> Option<&PatchVersion> {
+        let (major, minor) = patch_of(game_version).split_once('.')?;
+        self.latest_for(major.parse().ok()?, minor.parse().ok()?)
+    }
+
+    /// Every version released during a season, e.g. all `13.x.y` versions.
+    pub fn season(&self, major: u32) -> impl Iterator<Item = &PatchVersion> {
+        self.0.iter().filter(move |v| v.major == major)
+    }
+}
+
+impl Deref for Versions {
+    type Target = [PatchVersion];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions() -> Versions {
+        serde_json::from_str(
+            r#"["14.3.1", "14.2.1", "13.24.1", "13.1.1", "14.3.0", "0.151.2", "lolpatch_3.7"]"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_regular_versions() {
+        assert_eq!("14.3.1".parse(), Ok(PatchVersion::new(14, 3, 1)));
+    }
+
+    #[test]
+    fn parses_legacy_versions() {
+        let version: PatchVersion = "lolpatch_3.7".parse().unwrap();
+        assert_eq!(version, PatchVersion { major: 3, minor: 7, build: 0, legacy: true });
+        assert_eq!(version.to_string(), "lolpatch_3.7");
+    }
+
+    #[test]
+    fn rejects_malformed_versions() {
+        assert!("14.3".parse::<PatchVersion>().is_err());
+        assert!("lolpatch_3.7.1".parse::<PatchVersion>().is_err());
+        assert!("14.3.x".parse::<PatchVersion>().is_err());
+    }
+
+    #[test]
+    fn orders_numerically() {
+        assert!(PatchVersion::new(13, 24, 1) < PatchVersion::new(14, 1, 1));
+        assert!(PatchVersion::new(14, 10, 1) > PatchVersion::new(14, 9, 1));
+    }
+
+    #[test]
+    fn orders_legacy_before_same_patch() {
+        let legacy: PatchVersion = "lolpatch_3.7".parse().unwrap();
+        assert!(legacy < PatchVersion::new(3, 7, 0));
+        assert!(legacy > PatchVersion::new(3, 6, 9));
+    }
+
+    #[test]
+    fn skips_unparseable_versions() {
+        let versions: Versions = serde_json::from_str(r#"["14.3.1", "14.3.x", "beta"]"#).unwrap();
+        assert_eq!(versions.0, vec![PatchVersion::new(14, 3, 1)]);
+    }
+
+    #[test]
+    fn finds_latest_build_of_patch() {
+        assert_eq!(versions().latest_for(14, 3), Some(&PatchVersion::new(14, 3, 1)));
+        assert_eq!(versions().latest_for(12, 1), None);
+    }
+
+    #[test]
+    fn finds_version_for_game_version() {
+        assert_eq!(
+            versions().for_game_version("14.3.558.2130"),
+            Some(&PatchVersion::new(14, 3, 1))
+        );
+        assert_eq!(versions().for_game_version("12.1.400.1"), None);
+        assert_eq!(versions().for_game_version("garbage"), None);
+    }
+
+    #[test]
+    fn trims_versions_to_patch() {
+        assert_eq!(patch_of("14.3.558.2130"), "14.3");
+        assert_eq!(patch_of("14.3.1"), "14.3");
+        assert_eq!(patch_of("14.3"), "14.3");
+    }
+
+    #[test]
+    fn finds_versions_in_season() {
+        let season: Vec<_> = versions().season(13).map(|v| v.to_string()).collect();
+        assert_eq!(season, vec!["13.24.1", "13.1.1"]);
+    }
+
+    #[test]
+    fn finds_latest_version() {
+        assert_eq!(versions().latest(), Some(&PatchVersion::new(14, 3, 1)));
+    }
+}
@@ -0,0 +1,393 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::collections::{BTreeSet, HashSet};
+
+use url::Url;
+
+use crate::{
+    ClientError,
+    endpoint::{DATA_ENDPOINTS, data_resource},
+    models::{ChampionsFull, Items, Locale, SummonerSpells, shared::Image},
+    source::Resource,
+};
+
+/// Describes what to download when warming the cache for a patch.
+///
+/// ```
+/// use ddragon::prefetch::PrefetchPlan;
+///
+/// let data_only = PrefetchPlan::default();
+/// let with_icons = PrefetchPlan::default().champions(true).images(true);
+/// let everything = PrefetchPlan::everything();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchPlan {
+    data: bool,
+    champions: bool,
+    images: bool,
+    sprites: bool,
+}
+
+impl PrefetchPlan {
+    /// A plan that downloads every data endpoint, every individual champion,
+    /// and every image and sprite.
+    pub fn everything() -> Self {
+        Self { data: true, champions: true, images: true, sprites: true }
+    }
+
+    /// Whether to download every data endpoint, e.g. `item.json`. Enabled by
+    /// default.
+    pub fn data(mut self, data: bool) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Whether to download `champion/<key>.json` for every champion.
+    pub fn champions(mut self, champions: bool) -> Self {
+        self.champions = champions;
+        self
+    }
+
+    /// Whether to download the icons of every champion, champion spell,
+    /// passive, item and summoner spell.
+    pub fn images(mut self, images: bool) -> Self {
+        self.images = images;
+        self
+    }
+
+    /// Whether to download every sprite sheet the icons are packed into.
+    pub fn sprites(mut self, sprites: bool) -> Self {
+        self.sprites = sprites;
+        self
+    }
+
+    pub(crate) fn needs_champions(&self) -> bool {
+        self.champions || self.images || self.sprites
+    }
+
+    pub(crate) fn needs_images(&self) -> bool {
+        self.images || self.sprites
+    }
+
+    /// The endpoints of every individual champion, relative to the data URL.
+    fn champion_endpoints(&self, champions: &ChampionsFull) -> Vec<String> {
+        if !self.champions {
+            return vec![];
+        }
+        let keys: BTreeSet<_> = champions.data.values().map(|c| &c.id).collect();
+        keys.into_iter().map(|key| format!("champion/{key}.json")).collect()
+    }
+
+    /// The paths of every image and sprite, relative to the image URL.
+    fn image_paths(&self, images: &[&Image]) -> Vec<String> {
+        let mut paths = BTreeSet::new();
+        for image in images {
+            if self.images {
+                paths.insert(image.image_path());
+            }
+            if self.sprites {
+                paths.insert(image.sprite_path());
+            }
+        }
+        paths.into_iter().collect()
+    }
+}
+
+/// Only downloads the data endpoints.
+impl Default for PrefetchPlan {
+    fn default() -> Self {
+        Self { data: true, champions: false, images: false, sprites: false }
+    }
+}
+
+/// Collects every image belonging to champions, their spells and passives,
+/// items and summoner spells.
+fn images_of<'a>(
+    champions: Option<&'a ChampionsFull>,
+    items: Option<&'a Items>,
+    summoner_spells: Option<&'a SummonerSpells>,
+) -> Vec<&'a Image> {
+    let mut images = vec![];
+    for champion in champions.iter().flat_map(|c| c.data.values()) {
+        images.push(&champion.image);
+        images.push(&champion.passive.image);
+        images.extend(champion.spells.iter().map(|s| &s.image));
+    }
+    images.extend(items.iter().flat_map(|i| i.data.values()).map(|i| &i.image));
+    images.extend(summoner_spells.iter().flat_map(|s| s.data.values()).map(|s| &s.image));
+    images
+}
+
+/// A listing a client downloaded up front, to find what else a prefetch
+/// covers.
+pub(crate) struct Listing<T> {
+    pub(crate) resource: Resource,
+    /// The listing, and whether it had to be downloaded.
+    pub(crate) data: Result<(T, bool), ClientError>,
+}
+
+/// The listings needed by a plan, which [PrefetchPlan::needs_champions] and
+/// [PrefetchPlan::needs_images] decide.
+#[derive(Default)]
+pub(crate) struct Listings {
+    pub(crate) champions: Option<Listing<ChampionsFull>>,
+    pub(crate) items: Option<Listing<Items>>,
+    pub(crate) summoner_spells: Option<Listing<SummonerSpells>>,
+}
+
+/// A resource a prefetch covers, by URL. Listings come with whether they had
+/// to be downloaded, as they already were.
+pub(crate) type Pending = (String, Resource, Option<bool>);
+
+/// Plans a prefetch from its listings, and reports on it while each client
+/// downloads the rest.
+pub(crate) struct PrefetchQueue {
+    pending: Vec<Pending>,
+    report: PrefetchReport,
+    stored: bool,
+    completed: usize,
+    total: usize,
+}
+
+impl PrefetchQueue {
+    /// Lists every resource covered by `plan`, using `url` to find where each
+    /// one is requested from. `stored` is whether the client has a cache
+    /// directory that downloads are known to be stored in.
+    pub(crate) fn new(
+        plan: PrefetchPlan,
+        version: &str,
+        locale: &Locale,
+        listings: Listings,
+        stored: bool,
+        url: impl Fn(&Resource) -> Result<Url, url::ParseError>,
+    ) -> Result<Self, ClientError> {
+        let mut report = PrefetchReport::default();
+        let mut resources = vec![];
+        if plan.data {
+            resources.extend(DATA_ENDPOINTS.iter().map(|e| data_resource(version, locale, e)));
+        }
+
+        // Listings are reported along with everything else.
+        let mut listed = vec![];
+        let champions = take_listing(listings.champions, &mut listed, &mut report, &url)?;
+        let items = take_listing(listings.items, &mut listed, &mut report, &url)?;
+        let summoner_spells =
+            take_listing(listings.summoner_spells, &mut listed, &mut report, &url)?;
+        resources.extend(listed.iter().map(|(resource, _)| resource.clone()));
+
+        let champion_endpoints = champions.iter().flat_map(|c| plan.champion_endpoints(c));
+        resources.extend(champion_endpoints.map(|e| data_resource(version, locale, &e)));
+        let images = images_of(champions.as_ref(), items.as_ref(), summoner_spells.as_ref());
+        resources.extend(
+            plan.image_paths(&images)
+                .into_iter()
+                .map(|path| Resource::Image { version: version.to_owned(), path }),
+        );
+
+        let mut pending = vec![];
+        let mut seen = HashSet::new();
+        for resource in resources {
+            let url = url(&resource)?.to_string();
+            if !report.failed.iter().any(|(failed, _)| *failed == url) && seen.insert(url.clone()) {
+                let fetched = listed.iter().find(|(l, _)| *l == resource).map(|(_, f)| *f);
+                pending.push((url, resource, fetched));
+            }
+        }
+
+        let total = pending.len();
+        Ok(Self { pending, report, stored, completed: 0, total })
+    }
+
+    /// Takes every resource left to handle.
+    pub(crate) fn take_pending(&mut self) -> Vec<Pending> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Records what happened to a resource, where `Ok(true)` means it was
+    /// downloaded, and reports the progress made so far.
+    pub(crate) fn record(
+        &mut self,
+        url: String,
+        result: Result<bool, ClientError>,
+        on_progress: &mut impl FnMut(PrefetchProgress<'_>),
+    ) {
+        let status = match result {
+            Ok(true) if self.stored => {
+                self.report.fetched.push(url.clone());
+                PrefetchStatus::Fetched
+            }
+            Ok(true) => {
+                self.report.uncached.push(url.clone());
+                PrefetchStatus::Uncached
+            }
+            Ok(false) => {
+                self.report.skipped.push(url.clone());
+                PrefetchStatus::Skipped
+            }
+            Err(e) => {
+                self.report.failed.push((url.clone(), e));
+                PrefetchStatus::Failed
+            }
+        };
+        self.completed += 1;
+        on_progress(PrefetchProgress {
+            url: &url,
+            status,
+            completed: self.completed,
+            total: self.total,
+        });
+    }
+
+    pub(crate) fn into_report(self) -> PrefetchReport {
+        self.report
+    }
+}
+
+/// Returns the data of a listing, noting whether it had to be downloaded, or
+/// reporting it as failed.
+fn take_listing<T>(
+    listing: Option<Listing<T>>,
+    listed: &mut Vec<(Resource, bool)>,
+    report: &mut PrefetchReport,
+    url: impl Fn(&Resource) -> Result<Url, url::ParseError>,
+) -> Result<Option<T>, ClientError> {
+    let Some(Listing { resource, data }) = listing else {
+        return Ok(None);
+    };
+    match data {
+        Ok((data, fetched)) => {
+            listed.push((resource, fetched));
+            Ok(Some(data))
+        }
+        Err(e) => {
+            report.failed.push((url(&resource)?.to_string(), e));
+            Ok(None)
+        }
+    }
+}
+
+/// What happened to a single resource during a prefetch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefetchStatus {
+    /// The resource was downloaded and cached.
+    Fetched,
+    /// The resource was downloaded, but the client has no cache directory it
+    /// is known to be stored in, e.g. as it uses a custom agent.
+    Uncached,
+    /// The resource was already cached, so nothing was downloaded.
+    Skipped,
+    /// The resource could not be downloaded.
+    Failed,
+}
+
+/// Passed to the progress callback after each resource is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchProgress<'a> {
+    /// The URL of the resource.
+    pub url: &'a str,
+    /// What happened to the resource.
+    pub status: PrefetchStatus,
+    /// How many resources have been handled so far, including this one.
+    pub completed: usize,
+    /// How many resources the plan covers in total.
+    pub total: usize,
+}
+
+/// The outcome of a prefetch, listing the URL of every resource covered.
+#[derive(Debug, Default)]
+pub struct PrefetchReport {
+    /// Resources that were downloaded and cached.
+    pub fetched: Vec<String>,
+    /// Resources that were downloaded, but may not have been cached. See
+    /// [PrefetchStatus::Uncached].
+    pub uncached: Vec<String>,
+    /// Resources that were already cached.
+    pub skipped: Vec<String>,
+    /// Resources that could not be downloaded, along with the reason why.
+    pub failed: Vec<(String, ClientError)>,
+}
+
+impl PrefetchReport {
+    /// Whether every resource is now known to be cached.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.uncached.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(group: &str, full: &str, sprite: &str) -> Image {
+        Image {
+            full: full.to_owned(),
+            sprite: sprite.to_owned(),
+            group: group.to_owned(),
+            ..crate::fixtures::image()
+        }
+    }
+
+    #[test]
+    fn image_paths_are_deduplicated() {
+        let ahri = image("champion", "Ahri.png", "champion0.png");
+        let annie = image("champion", "Annie.png", "champion0.png");
+        let plan = PrefetchPlan::default().images(true).sprites(true);
+
+        assert_eq!(
+            plan.image_paths(&[&ahri, &annie, &ahri]),
+            vec!["champion/Ahri.png", "champion/Annie.png", "sprite/champion0.png"]
+        );
+    }
+
+    #[test]
+    fn image_paths_respect_plan() {
+        let ahri = image("champion", "Ahri.png", "champion0.png");
+
+        assert_eq!(
+            PrefetchPlan::default().sprites(true).image_paths(&[&ahri]),
+            vec!["sprite/champion0.png"]
+        );
+        assert!(PrefetchPlan::default().image_paths(&[&ahri]).is_empty());
+    }
+
+    #[test]
+    fn queue_records_outcomes() {
+        let plan = PrefetchPlan::default().data(false);
+        let url = |_: &Resource| Url::parse("https://example.com");
+        let mut queue =
+            PrefetchQueue::new(plan, "14.3.1", &Locale::EnUs, Listings::default(), true, url)
+                .unwrap();
+        let mut statuses = vec![];
+        let mut on_progress = |p: PrefetchProgress<'_>| statuses.push(p.status);
+
+        queue.record("a".to_owned(), Ok(true), &mut on_progress);
+        queue.record("b".to_owned(), Ok(false), &mut on_progress);
+        assert!(queue.report.is_complete());
+        queue.record("c".to_owned(), Err(ClientError::NoLatestVersion), &mut on_progress);
+        assert!(!queue.into_report().is_complete());
+        assert_eq!(
+            statuses,
+            vec![PrefetchStatus::Fetched, PrefetchStatus::Skipped, PrefetchStatus::Failed]
+        );
+    }
+
+    #[test]
+    fn queue_without_a_cache_reports_downloads_as_uncached() {
+        let plan = PrefetchPlan::default();
+        let url = |r: &Resource| Url::parse("https://example.com").unwrap().join(&r.path());
+        let mut queue =
+            PrefetchQueue::new(plan, "14.3.1", &Locale::EnUs, Listings::default(), false, url)
+                .unwrap();
+
+        let pending = queue.take_pending();
+        assert_eq!(pending.len(), DATA_ENDPOINTS.len());
+        for (url, _, _) in pending {
+            queue.record(url, Ok(true), &mut |p| assert_eq!(p.status, PrefetchStatus::Uncached));
+        }
+        let report = queue.into_report();
+        assert!(report.fetched.is_empty());
+        assert_eq!(report.uncached.len(), DATA_ENDPOINTS.len());
+        assert!(!report.is_complete());
+    }
+}
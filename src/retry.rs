@@ -0,0 +1,243 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use crate::ClientError;
+
+/// Describes when and how failed requests are retried. Applies to every
+/// request a client makes over the network, including images.
+///
+/// Clients don't retry unless configured with a policy. The default policy
+/// makes up to 3 attempts, waiting 500ms and then 1s (with jitter) between
+/// them, and retries the statuses and IO errors most likely to be transient.
+///
+/// ```
+/// use std::{io::ErrorKind, time::Duration};
+/// use ddragon::retry::RetryPolicy;
+///
+/// let patient = RetryPolicy::default()
+///     .max_attempts(5)
+///     .backoff(Duration::from_secs(1), Duration::from_secs(30))
+///     .statuses(&[429, 503])
+///     .io_errors(&[ErrorKind::ConnectionReset, ErrorKind::TimedOut]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    statuses: Vec<u16>,
+    io_errors: Vec<ErrorKind>,
+    retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries, which clients use unless configured
+    /// otherwise.
+    pub fn never() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// How many times a request is attempted in total, including the first
+    /// attempt.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// How long to wait before the first retry. The wait doubles with every
+    /// retry after that, up to `max`.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Whether to randomize each wait between half and all of the backoff, so
+    /// many clients failing at once don't all retry at the same time.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Which HTTP statuses are retried.
+    pub fn statuses(mut self, statuses: &[u16]) -> Self {
+        self.statuses = statuses.to_vec();
+        self
+    }
+
+    /// Which kinds of IO errors are retried. Timeouts are treated as
+    /// [ErrorKind::TimedOut], and failing to connect as
+    /// [ErrorKind::ConnectionRefused].
+    pub fn io_errors(mut self, io_errors: &[ErrorKind]) -> Self {
+        self.io_errors = io_errors.to_vec();
+        self
+    }
+
+    /// Whether to wait as long as a response's `Retry-After` header asks,
+    /// instead of the backoff. The wait is still capped at the maximum
+    /// backoff, and only the number of seconds form of the header is
+    /// understood.
+    pub fn honor_retry_after(mut self, retry_after: bool) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// How long to wait before retrying after `attempt` (counting from 1)
+    /// failed with `error`, or `None` if it shouldn't be retried.
+    pub(crate) fn delay(
+        &self,
+        attempt: u32,
+        error: &ClientError,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.retries(error) {
+            return None;
+        }
+        if let Some(retry_after) = retry_after.filter(|_| self.retry_after) {
+            return Some(retry_after.min(self.max_backoff));
+        }
+
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_backoff);
+        Some(if self.jitter { jittered(backoff) } else { backoff })
+    }
+
+    fn retries(&self, error: &ClientError) -> bool {
+        match error {
+            ClientError::HttpStatus { status, .. } => self.statuses.contains(status),
+            #[cfg(feature = "sync")]
+            ClientError::Request(error) => match error.as_ref() {
+                ureq::Error::Io(error) => self.io_errors.contains(&error.kind()),
+                ureq::Error::Timeout(_) => self.io_errors.contains(&ErrorKind::TimedOut),
+                ureq::Error::ConnectionFailed => {
+                    self.io_errors.contains(&ErrorKind::ConnectionRefused)
+                }
+                _ => false,
+            },
+            #[cfg(feature = "async-base")]
+            ClientError::AsyncRequest(error) => self.retries_reqwest(error),
+            #[cfg(feature = "async-base")]
+            ClientError::AsyncMiddlewareRequest(reqwest_middleware::Error::Reqwest(error)) => {
+                self.retries_reqwest(error)
+            }
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "async-base")]
+    fn retries_reqwest(&self, error: &reqwest::Error) -> bool {
+        if error.is_timeout() {
+            return self.io_errors.contains(&ErrorKind::TimedOut);
+        }
+
+        // reqwest doesn't expose IO errors directly, so look for one among the
+        // causes of the error.
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+        while let Some(cause) = source {
+            if let Some(io_error) = cause.downcast_ref::<std::io::Error>() {
+                return self.io_errors.contains(&io_error.kind());
+            }
+            source = cause.source();
+        }
+        error.is_connect() && self.io_errors.contains(&ErrorKind::ConnectionRefused)
+    }
+}
+
+/// The default policy, which makes up to 3 attempts.
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            statuses: vec![408, 429, 500, 502, 503, 504],
+            io_errors: vec![
+                ErrorKind::ConnectionReset,
+                ErrorKind::ConnectionAborted,
+                ErrorKind::ConnectionRefused,
+                ErrorKind::BrokenPipe,
+                ErrorKind::TimedOut,
+                ErrorKind::UnexpectedEof,
+                ErrorKind::Interrupted,
+            ],
+            retry_after: true,
+        }
+    }
+}
+
+/// Parses the value of a `Retry-After` header given in seconds.
+pub(crate) fn retry_after(value: Option<&str>) -> Option<Duration> {
+    value?.trim().parse().ok().map(Duration::from_secs)
+}
+
+/// Picks a random duration between half of and all of `backoff`.
+fn jittered(backoff: Duration) -> Duration {
+    // Every `RandomState` is seeded differently, which is random enough to
+    // spread retries out without depending on a random number crate.
+    let random = RandomState::new().build_hasher().finish();
+    let half = backoff / 2;
+    half + half.mul_f64(random as f64 / u64::MAX as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unavailable() -> ClientError {
+        ClientError::HttpStatus { url: "url".to_owned(), status: 503 }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let policy = RetryPolicy::default()
+            .max_attempts(6)
+            .backoff(Duration::from_secs(1), Duration::from_secs(5))
+            .jitter(false);
+
+        let delays: Vec<_> =
+            (1..=6).map(|a| policy.delay(a, &unavailable(), None).map(|d| d.as_secs())).collect();
+        assert_eq!(delays, vec![Some(1), Some(2), Some(4), Some(5), Some(5), None]);
+    }
+
+    #[test]
+    fn jitter_stays_within_backoff() {
+        let policy = RetryPolicy::default().backoff(Duration::from_secs(2), Duration::from_secs(2));
+        let delay = policy.delay(1, &unavailable(), None).unwrap();
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+    }
+
+    #[test]
+    fn only_retryable_errors_are_retried() {
+        let policy = RetryPolicy::default();
+        let not_found = ClientError::NotFound { url: "url".to_owned() };
+        let forbidden = ClientError::HttpStatus { url: "url".to_owned(), status: 401 };
+
+        assert!(policy.delay(1, &unavailable(), None).is_some());
+        assert!(policy.delay(1, &not_found, None).is_none());
+        assert!(policy.delay(1, &forbidden, None).is_none());
+        assert!(RetryPolicy::never().delay(1, &unavailable(), None).is_none());
+    }
+
+    #[test]
+    fn retry_after_is_honored_and_capped() {
+        let policy = RetryPolicy::default().backoff(Duration::ZERO, Duration::from_secs(30));
+        let retry_after = retry_after(Some("120"));
+
+        assert_eq!(retry_after, Some(Duration::from_secs(120)));
+        assert_eq!(policy.delay(1, &unavailable(), retry_after), Some(Duration::from_secs(30)));
+        assert_eq!(
+            policy.honor_retry_after(false).delay(1, &unavailable(), retry_after),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(super::retry_after(Some("Wed, 21 Oct 2015 07:28:00 GMT")), None);
+    }
+}
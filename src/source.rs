@@ -0,0 +1,884 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+#![warn(missing_docs)]
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Component, Path, PathBuf},
+    time::Duration,
+};
+
+#[cfg(feature = "async-base")]
+use std::sync::Arc;
+#[cfg(feature = "sync")]
+use std::thread;
+#[cfg(feature = "sync")]
+use ureq::{Agent, Body, http::Response};
+use url::Url;
+
+use crate::cache_policy::OFFLINE_MISS;
+use crate::retry::{RetryPolicy, retry_after};
+
+#[cfg(feature = "dragontail")]
+use std::{
+    collections::BTreeSet,
+    io::{BufRead, BufReader, Read},
+};
+
+use crate::{
+    ClientError,
+    models::{Locale, PatchVersion},
+};
+
+/// A single piece of data a client can request, independent of where it is
+/// stored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// The list of every published version.
+    Versions,
+    /// The list of languages data is published in.
+    Languages,
+    /// A data file for a version and locale, e.g. `item.json` or
+    /// `champion/MonkeyKing.json`.
+    Data {
+        /// The ddragon version, e.g. `14.3.1`.
+        version: String,
+        /// The language of the data.
+        locale: Locale,
+        /// The path of the file within the data directory.
+        path: String,
+    },
+    /// An image for a version, e.g. `champion/MonkeyKing.png` or
+    /// `sprite/champion2.png`.
+    Image {
+        /// The ddragon version, e.g. `14.3.1`.
+        version: String,
+        /// The path of the file within the image directory.
+        path: String,
+    },
+    /// A cdragon data file for a patch, e.g. `arena/en_us.json`.
+    #[cfg(feature = "cdragon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cdragon")))]
+    CDragon {
+        /// The patch, e.g. `14.3`.
+        patch: String,
+        /// The path of the file within the cdragon directory.
+        path: String,
+    },
+}
+
+impl Resource {
+    /// The path of the resource on the server it is served from, e.g.
+    /// `cdn/14.3.1/data/en_US/item.json`.
+    pub fn path(&self) -> String {
+        match self {
+            Resource::Versions => "api/versions.json".to_owned(),
+            Resource::Languages => "cdn/languages.json".to_owned(),
+            Resource::Data { version, locale, path } => {
+                format!("cdn/{version}/data/{locale}/{path}")
+            }
+            Resource::Image { version, path } => format!("cdn/{version}/img/{path}"),
+            #[cfg(feature = "cdragon")]
+            Resource::CDragon { patch, path } => format!("{patch}/cdragon/{path}"),
+        }
+    }
+
+    /// The path of the resource within a dragontail archive, e.g.
+    /// `14.3.1/data/en_US/item.json`. Archives don't include the version list
+    /// or any cdragon data.
+    ///
+    /// Paths that could point outside the archive, e.g. for a champion key
+    /// like `../../secrets`, are rejected with `None` too.
+    pub fn dragontail_path(&self) -> Option<String> {
+        let path = match self {
+            Resource::Versions => None,
+            #[cfg(feature = "cdragon")]
+            Resource::CDragon { .. } => None,
+            _ => self.path().strip_prefix("cdn/").map(|p| p.to_owned()),
+        }?;
+        Path::new(&path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+            .then_some(path)
+    }
+}
+
+/// Provides the raw bytes of resources, so a client can serve data from
+/// somewhere other than the ddragon servers.
+///
+/// Sources are synchronous, and may block. The async client runs them with
+/// [BlockingSource], so they never block its executor.
+pub trait DataSource: Send + Sync {
+    /// Returns the raw bytes of a resource, or [ClientError::NotFound] if the
+    /// source doesn't have it.
+    fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError>;
+}
+
+/// The async counterpart of [DataSource], which
+/// [AsyncClient](crate::AsyncClient) reads everything through.
+#[cfg(feature = "async-base")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[async_trait::async_trait]
+pub trait AsyncDataSource: Send + Sync {
+    /// Returns the raw bytes of a resource, or [ClientError::NotFound] if the
+    /// source doesn't have it.
+    async fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError>;
+}
+
+/// Runs a [DataSource] on tokio's blocking thread pool, so it can be used as
+/// an [AsyncDataSource]. This is how
+/// [AsyncClientBuilder::source](crate::AsyncClientBuilder::source) adapts
+/// synchronous sources.
+///
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use ddragon::{AsyncClientBuilder, source::{BlockingSource, DirectorySource}};
+///
+/// let source = BlockingSource::new(DirectorySource::new("./dragontail"));
+/// let client = AsyncClientBuilder::new().async_source(source).build().await.unwrap();
+/// # })
+/// ```
+#[cfg(feature = "async-base")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[derive(Clone)]
+pub struct BlockingSource {
+    source: Arc<dyn DataSource>,
+}
+
+#[cfg(feature = "async-base")]
+impl BlockingSource {
+    /// Wraps a synchronous source.
+    pub fn new(source: impl DataSource + 'static) -> Self {
+        Self { source: Arc::new(source) }
+    }
+}
+
+#[cfg(feature = "async-base")]
+#[async_trait::async_trait]
+impl AsyncDataSource for BlockingSource {
+    async fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        let source = self.source.clone();
+        let resource = resource.clone();
+        match tokio::task::spawn_blocking(move || source.fetch(&resource)).await {
+            Ok(result) => result,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+}
+
+/// Serves resources from the ddragon servers (and the CommunityDragon
+/// servers, for cdragon data). This is what [Client](crate::Client) uses
+/// unless another source is configured.
+///
+/// ```no_run
+/// use ureq::Agent;
+/// use ddragon::{ClientBuilder, source::HttpSource};
+///
+/// let source = HttpSource::new(Agent::new_with_defaults());
+/// let client = ClientBuilder::new().source(source).build().unwrap();
+/// ```
+#[cfg(feature = "sync")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+#[derive(Debug, Clone)]
+pub struct HttpSource {
+    agent: Agent,
+    offline: bool,
+    retry: RetryPolicy,
+    base_url: Url,
+    #[cfg(feature = "cdragon")]
+    cdragon_base_url: Url,
+}
+
+#[cfg(feature = "sync")]
+impl HttpSource {
+    /// Creates a source that makes requests using `agent`. Any caching is up
+    /// to the agent's middleware, e.g.
+    /// [CacheMiddleware](crate::cache_middleware::CacheMiddleware).
+    pub fn new(agent: Agent) -> Self {
+        Self {
+            agent,
+            offline: false,
+            retry: RetryPolicy::never(),
+            base_url: Url::parse("https://ddragon.leagueoflegends.com").unwrap(),
+            #[cfg(feature = "cdragon")]
+            cdragon_base_url: Url::parse("https://raw.communitydragon.org").unwrap(),
+        }
+    }
+
+    /// Reports uncached resources as [ClientError::NotCached]. The agent must
+    /// be configured with an offline
+    /// [CacheMiddleware](crate::cache_middleware::CacheMiddleware).
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Configures when failed requests are retried. Requests aren't retried
+    /// unless this is set.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    pub(crate) fn server(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    #[cfg(feature = "cdragon")]
+    pub(crate) fn cdragon_server(mut self, cdragon_base_url: Url) -> Self {
+        self.cdragon_base_url = cdragon_base_url;
+        self
+    }
+
+    /// The URL a resource is requested from.
+    pub fn url(&self, resource: &Resource) -> Result<Url, url::ParseError> {
+        match resource {
+            #[cfg(feature = "cdragon")]
+            Resource::CDragon { .. } => self.cdragon_base_url.join(&resource.path()),
+            _ => self.base_url.join(&resource.path()),
+        }
+    }
+
+    fn get(&self, request_url: &str) -> Result<Response<Body>, ClientError> {
+        // Error statuses are handled by `attempt`, which needs the headers.
+        let response = self
+            .agent
+            .get(request_url)
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .call()
+            .map_err(Box::new)?;
+
+        // The cache middleware answers with a marked gateway timeout when it
+        // can't serve a request offline.
+        if self.offline && response.status() == 504 && response.headers().contains_key(OFFLINE_MISS)
+        {
+            return Err(ClientError::NotCached { url: request_url.to_owned() });
+        }
+
+        Ok(response)
+    }
+
+    /// Makes a single request, returning how long the server asked to wait
+    /// before trying again if it fails.
+    fn attempt(&self, request_url: &str) -> (Result<Vec<u8>, ClientError>, Option<Duration>) {
+        let response = match self.get(request_url) {
+            Ok(response) => response,
+            Err(e) => return (Err(e), None),
+        };
+        if !response.status().is_success() {
+            let header = response.headers().get("retry-after").and_then(|v| v.to_str().ok());
+            let error = status_error(request_url, response.status().as_u16());
+            return (Err(error), retry_after(header));
+        }
+        (response.into_body().read_to_vec().map_err(|e| Box::new(e).into()), None)
+    }
+}
+
+/// Serves resources from the ddragon servers (and the CommunityDragon
+/// servers, for cdragon data) using `reqwest`. This is what
+/// [AsyncClient](crate::AsyncClient) uses unless another source is
+/// configured.
+///
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use reqwest_middleware::ClientBuilder;
+/// use ddragon::{AsyncClientBuilder, source::AsyncHttpSource};
+///
+/// let source = AsyncHttpSource::new(ClientBuilder::new(reqwest::Client::new()).build());
+/// let client = AsyncClientBuilder::new().async_source(source).build().await.unwrap();
+/// # })
+/// ```
+#[cfg(feature = "async-base")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[derive(Clone)]
+pub struct AsyncHttpSource {
+    agent: reqwest_middleware::ClientWithMiddleware,
+    offline: bool,
+    retry: RetryPolicy,
+    base_url: Url,
+    #[cfg(feature = "cdragon")]
+    cdragon_base_url: Url,
+}
+
+#[cfg(feature = "async-base")]
+impl AsyncHttpSource {
+    /// Creates a source that makes requests using `agent`. Any caching is up
+    /// to the agent's middleware, e.g.
+    /// [AsyncCacheMiddleware](crate::async_cache_middleware::AsyncCacheMiddleware).
+    pub fn new(agent: reqwest_middleware::ClientWithMiddleware) -> Self {
+        Self {
+            agent,
+            offline: false,
+            retry: RetryPolicy::never(),
+            base_url: Url::parse("https://ddragon.leagueoflegends.com").unwrap(),
+            #[cfg(feature = "cdragon")]
+            cdragon_base_url: Url::parse("https://raw.communitydragon.org").unwrap(),
+        }
+    }
+
+    /// Reports uncached resources as [ClientError::NotCached]. The agent must
+    /// be configured with an offline
+    /// [AsyncCacheMiddleware](crate::async_cache_middleware::AsyncCacheMiddleware).
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Configures when failed requests are retried. Requests aren't retried
+    /// unless this is set.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    pub(crate) fn server(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    #[cfg(feature = "cdragon")]
+    pub(crate) fn cdragon_server(mut self, cdragon_base_url: Url) -> Self {
+        self.cdragon_base_url = cdragon_base_url;
+        self
+    }
+
+    /// The URL a resource is requested from.
+    pub fn url(&self, resource: &Resource) -> Result<Url, url::ParseError> {
+        match resource {
+            #[cfg(feature = "cdragon")]
+            Resource::CDragon { .. } => self.cdragon_base_url.join(&resource.path()),
+            _ => self.base_url.join(&resource.path()),
+        }
+    }
+
+    async fn get(&self, request_url: &str) -> Result<reqwest::Response, ClientError> {
+        let response = self.agent.get(request_url).send().await?;
+
+        // The cache middleware answers with a marked gateway timeout when it
+        // can't serve a request offline.
+        if self.offline && response.status() == 504 && response.headers().contains_key(OFFLINE_MISS)
+        {
+            return Err(ClientError::NotCached { url: request_url.to_owned() });
+        }
+
+        Ok(response)
+    }
+
+    /// Makes a single request, returning how long the server asked to wait
+    /// before trying again if it fails.
+    async fn attempt(&self, request_url: &str) -> (Result<Vec<u8>, ClientError>, Option<Duration>) {
+        let response = match self.get(request_url).await {
+            Ok(response) => response,
+            Err(e) => return (Err(e), None),
+        };
+        // Unlike ureq, reqwest doesn't treat error statuses as failures.
+        if !response.status().is_success() {
+            let header = response.headers().get("retry-after").and_then(|v| v.to_str().ok());
+            let error = status_error(request_url, response.status().as_u16());
+            return (Err(error), retry_after(header));
+        }
+        (response.bytes().await.map(|b| b.to_vec()).map_err(|e| e.into()), None)
+    }
+}
+
+#[cfg(feature = "async-base")]
+#[async_trait::async_trait]
+impl AsyncDataSource for AsyncHttpSource {
+    async fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        let request_url = self.url(resource)?;
+        let mut attempt = 1;
+        loop {
+            let (result, retry_after) = self.attempt(request_url.as_str()).await;
+            match result {
+                Err(e) => match self.retry.delay(attempt, &e, retry_after) {
+                    Some(delay) => tokio::time::sleep(delay).await,
+                    None => return Err(e),
+                },
+                data => return data,
+            }
+            attempt += 1;
+        }
+    }
+}
+
+/// The error for a response with an error status.
+pub(crate) fn status_error(url: &str, status: u16) -> ClientError {
+    match status {
+        // ddragon is served from S3, which answers with a 403 instead of a 404
+        // for files that don't exist.
+        403 | 404 => ClientError::NotFound { url: url.to_owned() },
+        status => ClientError::HttpStatus { url: url.to_owned(), status },
+    }
+}
+
+#[cfg(feature = "sync")]
+impl DataSource for HttpSource {
+    fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        let request_url = self.url(resource)?;
+        let mut attempt = 1;
+        loop {
+            let (result, retry_after) = self.attempt(request_url.as_str());
+            match result {
+                Err(e) => match self.retry.delay(attempt, &e, retry_after) {
+                    Some(delay) => thread::sleep(delay),
+                    None => return Err(e),
+                },
+                data => return data,
+            }
+            attempt += 1;
+        }
+    }
+}
+
+/// Serves resources from memory, e.g. fixtures in tests.
+///
+/// ```
+/// use ddragon::{ClientBuilder, source::{MemorySource, Resource}};
+///
+/// let source = MemorySource::new()
+///     .with(Resource::Versions, r#"["14.3.1"]"#)
+///     .with(Resource::Languages, r#"["en_US"]"#);
+/// let client = ClientBuilder::new().source(source).build().unwrap();
+/// assert_eq!(client.version, "14.3.1");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemorySource {
+    resources: HashMap<Resource, Vec<u8>>,
+}
+
+impl MemorySource {
+    /// Creates a source with no resources.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a resource, replacing any previous data for it.
+    pub fn with(mut self, resource: Resource, data: impl Into<Vec<u8>>) -> Self {
+        self.resources.insert(resource, data.into());
+        self
+    }
+}
+
+impl DataSource for MemorySource {
+    fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        self.resources
+            .get(resource)
+            .cloned()
+            .ok_or_else(|| ClientError::NotFound { url: resource.path() })
+    }
+}
+
+/// Tries several sources in order, returning the first resource found. If
+/// every source fails, the error from the last one is returned.
+///
+/// ```no_run
+/// use ureq::Agent;
+/// use ddragon::{
+///     ClientBuilder,
+///     source::{DirectorySource, HttpSource, LayeredSource},
+/// };
+///
+/// let source = LayeredSource::new()
+///     .layer(DirectorySource::new("./dragontail"))
+///     .layer(HttpSource::new(Agent::new_with_defaults()));
+/// let client = ClientBuilder::new().source(source).build().unwrap();
+/// ```
+#[derive(Default)]
+pub struct LayeredSource {
+    layers: Vec<Box<dyn DataSource>>,
+}
+
+impl LayeredSource {
+    /// Creates a source with no layers, which never finds anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a source to try after every source added so far.
+    pub fn layer(mut self, source: impl DataSource + 'static) -> Self {
+        self.layers.push(Box::new(source));
+        self
+    }
+}
+
+impl DataSource for LayeredSource {
+    fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        let mut last_error = ClientError::NotFound { url: resource.path() };
+        for layer in &self.layers {
+            match layer.fetch(resource) {
+                Ok(data) => return Ok(data),
+                Err(e) => last_error = e,
+            }
+        }
+        Err(last_error)
+    }
+}
+
+/// Lists versions newest first, the same as the ddragon version list.
+fn versions_json(versions: impl IntoIterator<Item = PatchVersion>) -> Result<Vec<u8>, ClientError> {
+    let mut versions: Vec<_> = versions.into_iter().collect();
+    versions.sort_by(|a, b| b.cmp(a));
+    Ok(serde_json::to_vec(&versions).map_err(io::Error::from)?)
+}
+
+/// Serves resources from an extracted dragontail archive, the
+/// `dragontail-<version>.tgz` file published for every patch.
+///
+/// The version list is made up of the version directories found in the
+/// archive, so several patches can be extracted into the same directory.
+///
+/// ```no_run
+/// use ddragon::{ClientBuilder, source::DirectorySource};
+///
+/// let client = ClientBuilder::new().source(DirectorySource::new("./dragontail")).build().unwrap();
+/// let items = client.items().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    root: PathBuf,
+}
+
+impl DirectorySource {
+    /// Creates a source reading from the root of an extracted archive, which
+    /// contains directories like `14.3.1` and `img`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl DataSource for DirectorySource {
+    fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        if *resource == Resource::Versions {
+            let versions = fs::read_dir(&self.root)
+                .map_err(|source| ClientError::Io { path: self.root.clone(), source })?
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok());
+            return versions_json(versions);
+        }
+
+        let not_found = || ClientError::NotFound { url: resource.path() };
+        let path = self.root.join(resource.dragontail_path().ok_or_else(not_found)?);
+        fs::read(&path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => not_found(),
+            _ => ClientError::Io { path, source },
+        })
+    }
+}
+
+/// Serves resources from a dragontail archive without extracting it. Both
+/// gzipped (`.tgz`) and plain (`.tar`) archives are supported.
+///
+/// Archives can't be read from at random, so every file is loaded into
+/// memory when the archive is opened. Full archives are several gigabytes, so
+/// use [TarballSource::open_filtered] to only keep what's needed, or extract
+/// the archive and use [DirectorySource] instead.
+///
+/// ```no_run
+/// use ddragon::{ClientBuilder, source::TarballSource};
+///
+/// let source = TarballSource::open_filtered("./dragontail-14.3.1.tgz", |path| {
+///     path.contains("/data/en_US/")
+/// })
+/// .unwrap();
+/// let client = ClientBuilder::new().source(source).build().unwrap();
+/// let champions = client.champions().unwrap();
+/// ```
+#[cfg(feature = "dragontail")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dragontail")))]
+#[derive(Debug, Clone)]
+pub struct TarballSource {
+    files: HashMap<String, Vec<u8>>,
+    versions: BTreeSet<PatchVersion>,
+}
+
+#[cfg(feature = "dragontail")]
+impl TarballSource {
+    /// Loads every file in an archive.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ClientError> {
+        Self::open_filtered(path, |_| true)
+    }
+
+    /// Loads the files in an archive for which `keep` returns true, given the
+    /// path of the file within the archive, e.g. `14.3.1/data/en_US/item.json`.
+    pub fn open_filtered(
+        path: impl AsRef<Path>,
+        keep: impl Fn(&str) -> bool,
+    ) -> Result<Self, ClientError> {
+        let path = path.as_ref();
+        let io_error = |source| ClientError::Io { path: path.to_owned(), source };
+        let file = fs::File::open(path).map_err(io_error)?;
+        Self::read(file, keep).map_err(io_error)
+    }
+
+    /// Loads the files for which `keep` returns true from an archive that is
+    /// already open, e.g. one being downloaded.
+    pub fn from_reader(
+        reader: impl Read,
+        keep: impl Fn(&str) -> bool,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::read(reader, keep)?)
+    }
+
+    fn read(reader: impl Read, keep: impl Fn(&str) -> bool) -> io::Result<Self> {
+        let mut reader = BufReader::new(reader);
+        if reader.fill_buf()?.starts_with(&[0x1f, 0x8b]) {
+            Self::read_archive(flate2::read::GzDecoder::new(reader), keep)
+        } else {
+            Self::read_archive(reader, keep)
+        }
+    }
+
+    fn read_archive(reader: impl Read, keep: impl Fn(&str) -> bool) -> io::Result<Self> {
+        let mut files = HashMap::new();
+        let mut versions = BTreeSet::new();
+        for entry in tar::Archive::new(reader).entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let path = entry.path()?.to_string_lossy().trim_start_matches("./").to_owned();
+            if let Some(version) = path.split('/').next().and_then(|v| v.parse().ok()) {
+                versions.insert(version);
+            }
+            if keep(&path) {
+                let mut data = vec![];
+                entry.read_to_end(&mut data)?;
+                files.insert(path, data);
+            }
+        }
+
+        Ok(Self { files, versions })
+    }
+}
+
+#[cfg(feature = "dragontail")]
+impl DataSource for TarballSource {
+    fn fetch(&self, resource: &Resource) -> Result<Vec<u8>, ClientError> {
+        if *resource == Resource::Versions {
+            return versions_json(self.versions.iter().copied());
+        }
+
+        resource
+            .dragontail_path()
+            .and_then(|path| self.files.get(&path))
+            .cloned()
+            .ok_or_else(|| ClientError::NotFound { url: resource.path() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env::temp_dir, fs::remove_dir_all};
+
+    fn item_data(version: &str) -> Resource {
+        Resource::Data {
+            version: version.to_owned(),
+            locale: Locale::EnUs,
+            path: "item.json".to_owned(),
+        }
+    }
+
+    fn create_dragontail(name: &str) -> PathBuf {
+        let root = temp_dir().join(name);
+        let _ = remove_dir_all(&root);
+        for (path, data) in [
+            ("14.3.1/data/en_US/item.json", "new items"),
+            ("14.2.1/data/en_US/item.json", "old items"),
+            ("14.3.1/img/item/1001.png", "boots"),
+            ("img/champion/splash/Ahri_0.jpg", "splash"),
+            ("languages.json", r#"["en_US"]"#),
+        ] {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, data).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn resources_map_to_dragontail_paths() {
+        let image = Resource::Image { version: "14.3.1".to_owned(), path: "item/1001.png".into() };
+        assert_eq!(item_data("14.3.1").dragontail_path().unwrap(), "14.3.1/data/en_US/item.json");
+        assert_eq!(image.path(), "cdn/14.3.1/img/item/1001.png");
+        assert_eq!(Resource::Languages.dragontail_path().unwrap(), "languages.json");
+        assert_eq!(Resource::Versions.dragontail_path(), None);
+    }
+
+    #[test]
+    fn directory_serves_files() {
+        let source = DirectorySource::new(create_dragontail("dragontail01"));
+
+        assert_eq!(source.fetch(&item_data("14.2.1")).unwrap(), b"old items");
+        assert_eq!(source.fetch(&Resource::Languages).unwrap(), br#"["en_US"]"#);
+        assert!(matches!(source.fetch(&item_data("14.1.1")), Err(ClientError::NotFound { .. })));
+    }
+
+    #[test]
+    fn directory_rejects_paths_outside_the_root() {
+        let root = create_dragontail("dragontail08");
+        let source = DirectorySource::new(root.join("14.3.1"));
+        let escaping = Resource::Data {
+            version: "..".to_owned(),
+            locale: Locale::EnUs,
+            path: "../../14.2.1/data/en_US/item.json".to_owned(),
+        };
+        let absolute = Resource::Data {
+            version: root.join("14.2.1").display().to_string(),
+            locale: Locale::EnUs,
+            path: "item.json".to_owned(),
+        };
+
+        assert_eq!(escaping.dragontail_path(), None);
+        assert_eq!(absolute.dragontail_path(), None);
+        assert!(matches!(source.fetch(&escaping), Err(ClientError::NotFound { .. })));
+        assert!(matches!(source.fetch(&absolute), Err(ClientError::NotFound { .. })));
+    }
+
+    #[test]
+    fn directory_reports_unreadable_paths() {
+        let root = create_dragontail("dragontail06");
+        fs::create_dir_all(root.join("14.1.1/data/en_US/item.json")).unwrap();
+        let source = DirectorySource::new(&root);
+
+        let error = source.fetch(&item_data("14.1.1")).unwrap_err();
+        assert!(matches!(&error, ClientError::Io { path, .. } if path.ends_with("item.json")));
+        assert!(error.to_string().starts_with("Could not read "));
+        let missing = DirectorySource::new(root.join("missing")).fetch(&Resource::Versions);
+        assert!(matches!(missing, Err(ClientError::Io { .. })));
+    }
+
+    #[test]
+    fn memory_serves_inserted_resources() {
+        let source = MemorySource::new().with(Resource::Languages, "[]");
+
+        assert_eq!(source.fetch(&Resource::Languages).unwrap(), b"[]");
+        assert!(matches!(source.fetch(&Resource::Versions), Err(ClientError::NotFound { .. })));
+    }
+
+    #[test]
+    fn layers_fall_back_in_order() {
+        let source = LayeredSource::new()
+            .layer(MemorySource::new().with(item_data("14.3.1"), "fixture"))
+            .layer(DirectorySource::new(create_dragontail("dragontail05")));
+
+        assert_eq!(source.fetch(&item_data("14.3.1")).unwrap(), b"fixture");
+        assert_eq!(source.fetch(&item_data("14.2.1")).unwrap(), b"old items");
+        assert!(matches!(source.fetch(&item_data("14.1.1")), Err(ClientError::NotFound { .. })));
+        assert!(matches!(
+            LayeredSource::new().fetch(&Resource::Languages),
+            Err(ClientError::NotFound { .. })
+        ));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn http_requests_resources() {
+        let mut server = mockito::Server::new();
+        let _mock = server.mock("GET", "/cdn/languages.json").with_body("[]").create();
+        let source =
+            HttpSource::new(Agent::new_with_defaults()).server(Url::parse(&server.url()).unwrap());
+
+        assert_eq!(source.fetch(&Resource::Languages).unwrap(), b"[]");
+        assert!(source.fetch(&Resource::Versions).is_err());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn http_reports_real_gateway_timeouts_offline() {
+        let mut server = mockito::Server::new();
+        let _mock = server.mock("GET", "/cdn/languages.json").with_status(504).create();
+        let source = HttpSource::new(Agent::new_with_defaults())
+            .offline(true)
+            .server(Url::parse(&server.url()).unwrap());
+
+        let error = source.fetch(&Resource::Languages).unwrap_err();
+        assert!(matches!(error, ClientError::HttpStatus { status: 504, .. }));
+    }
+
+    #[cfg(feature = "async-base")]
+    #[tokio::test]
+    async fn async_http_requests_resources() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server.mock("GET", "/cdn/languages.json").with_body("[]").create_async().await;
+        let agent = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+        let source = AsyncHttpSource::new(agent).server(Url::parse(&server.url()).unwrap());
+
+        assert_eq!(source.fetch(&Resource::Languages).await.unwrap(), b"[]");
+        assert!(source.fetch(&Resource::Versions).await.is_err());
+    }
+
+    #[cfg(feature = "async-base")]
+    #[tokio::test]
+    async fn async_http_reports_real_gateway_timeouts_offline() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server.mock("GET", "/cdn/languages.json").with_status(504).create_async().await;
+        let agent = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+        let source =
+            AsyncHttpSource::new(agent).offline(true).server(Url::parse(&server.url()).unwrap());
+
+        let error = source.fetch(&Resource::Languages).await.unwrap_err();
+        assert!(matches!(error, ClientError::HttpStatus { status: 504, .. }));
+    }
+
+    #[cfg(feature = "async-base")]
+    #[tokio::test]
+    async fn blocking_source_runs_sync_sources() {
+        let source = BlockingSource::new(DirectorySource::new(create_dragontail("dragontail07")));
+
+        assert_eq!(source.fetch(&item_data("14.2.1")).await.unwrap(), b"old items");
+        assert!(matches!(
+            source.fetch(&item_data("14.1.1")).await,
+            Err(ClientError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn directory_lists_versions_newest_first() {
+        let source = DirectorySource::new(create_dragontail("dragontail02"));
+        assert_eq!(source.fetch(&Resource::Versions).unwrap(), br#"["14.3.1","14.2.1"]"#);
+    }
+
+    #[cfg(feature = "dragontail")]
+    fn create_tarball(gzipped: bool) -> Vec<u8> {
+        let root = create_dragontail(if gzipped { "dragontail03" } else { "dragontail04" });
+        let mut builder = tar::Builder::new(vec![]);
+        builder.append_dir_all(".", root).unwrap();
+        let archive = builder.into_inner().unwrap();
+        if !gzipped {
+            return archive;
+        }
+
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::fast());
+        io::Write::write_all(&mut encoder, &archive).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[cfg(feature = "dragontail")]
+    #[test]
+    fn tarball_serves_files() {
+        for gzipped in [true, false] {
+            let source =
+                TarballSource::from_reader(&create_tarball(gzipped)[..], |_| true).unwrap();
+
+            assert_eq!(source.fetch(&item_data("14.3.1")).unwrap(), b"new items");
+            assert_eq!(source.fetch(&Resource::Versions).unwrap(), br#"["14.3.1","14.2.1"]"#);
+        }
+    }
+
+    #[cfg(feature = "dragontail")]
+    #[test]
+    fn tarball_skips_filtered_files() {
+        let source =
+            TarballSource::from_reader(&create_tarball(true)[..], |p| !p.starts_with("14.2.1"))
+                .unwrap();
+
+        assert!(source.fetch(&item_data("14.3.1")).is_ok());
+        assert!(matches!(source.fetch(&item_data("14.2.1")), Err(ClientError::NotFound { .. })));
+        // Filtered versions are still listed.
+        assert_eq!(source.fetch(&Resource::Versions).unwrap(), br#"["14.3.1","14.2.1"]"#);
+    }
+}
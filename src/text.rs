@@ -0,0 +1,397 @@
+#![warn(missing_docs)]
+
+/// Rich text in the pseudo-HTML Riot uses for descriptions and tooltips, e.g.
+/// `<mainText><stats><attention>20</attention> Ability Power</stats></mainText>`.
+///
+/// Parsing never fails. Any tag is accepted, tags left open are closed at the
+/// end, and closing tags that match nothing are dropped. A `<` that doesn't
+/// start a tag is kept as text.
+///
+/// ```
+/// use ddragon::text::RichText;
+///
+/// let text = RichText::parse("<stats><attention>20</attention> Ability Power</stats><br>Burns");
+/// assert_eq!(text.to_plain_text(), "20 Ability Power\nBurns");
+/// assert_eq!(text.to_markdown(), "**20** Ability Power  \nBurns");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichText {
+    /// The top level nodes of the text.
+    pub nodes: Vec<Node>,
+}
+
+/// A single piece of [RichText].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// Plain text, with entities like `&amp;` already decoded.
+    Text(String),
+    /// A `<br>` or `<hr>` tag.
+    LineBreak,
+    /// Any other tag, along with everything inside it.
+    Element(Element),
+}
+
+/// A tag in [RichText], e.g. `<stats>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Element {
+    /// The tag's name, in lowercase, e.g. `maintext`. Attributes aren't kept.
+    pub tag: String,
+    /// Everything inside the tag.
+    pub children: Vec<Node>,
+}
+
+enum Tag<'a> {
+    Open { name: &'a str, self_closing: bool },
+    Close { name: &'a str },
+}
+
+impl RichText {
+    /// Parses rich text, e.g. an item's description.
+    pub fn parse(source: &str) -> Self {
+        // Every open element, with the outermost (the text itself) first.
+        let mut open: Vec<(String, Vec<Node>)> = vec![(String::new(), vec![])];
+        let mut text = String::new();
+        let mut rest = source;
+
+        while let Some(start) = rest.find('<') {
+            text.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let Some((tag, length)) = parse_tag(rest) else {
+                text.push('<');
+                rest = &rest[1..];
+                continue;
+            };
+            rest = &rest[length..];
+
+            let (name, self_closing) = match tag {
+                Tag::Open { name, self_closing } => (name, self_closing),
+                Tag::Close { name } => {
+                    let matching =
+                        open.iter().skip(1).rposition(|(tag, _)| tag.eq_ignore_ascii_case(name));
+                    if let Some(index) = matching {
+                        push_text(&mut open.last_mut().unwrap().1, &mut text);
+                        close(&mut open, index + 1);
+                    }
+                    continue;
+                }
+            };
+
+            let nodes = &mut open.last_mut().unwrap().1;
+            push_text(nodes, &mut text);
+            if is_line_break(name) {
+                nodes.push(Node::LineBreak);
+            } else if self_closing {
+                let tag = name.to_ascii_lowercase();
+                nodes.push(Node::Element(Element { tag, children: vec![] }));
+            } else {
+                open.push((name.to_ascii_lowercase(), vec![]));
+            }
+        }
+
+        text.push_str(rest);
+        push_text(&mut open.last_mut().unwrap().1, &mut text);
+        close(&mut open, 1);
+        Self { nodes: open.pop().unwrap().1 }
+    }
+
+    /// Renders the text without any formatting, with line breaks as newlines.
+    pub fn to_plain_text(&self) -> String {
+        let mut output = String::new();
+        plain(&self.nodes, &mut output);
+        output
+    }
+
+    /// Renders the text as CommonMark, e.g. for a Discord message. Emphasized
+    /// tags like `<attention>` and `<passive>` become bold, and flavor text
+    /// and rules become italic. Everything else is kept as plain text.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        markdown(&self.nodes, &mut vec![], &mut output);
+        output
+    }
+
+    /// Renders the text as HTML, with every tag turned into a `<span>` with a
+    /// `ddragon-<tag>` class (e.g. `ddragon-attention`) to style it with.
+    /// Attributes are dropped, and all text is escaped, so the output is safe
+    /// to embed in a page.
+    pub fn to_html(&self) -> String {
+        let mut output = String::new();
+        html(&self.nodes, &mut output);
+        output
+    }
+
+    /// Renders the text with ANSI escape codes, to color it in a terminal.
+    /// Formatting is reset at the end of every styled tag, so nothing leaks
+    /// into whatever is printed next.
+    pub fn to_ansi(&self) -> String {
+        let mut output = String::new();
+        ansi(&self.nodes, &mut vec![], &mut output);
+        output
+    }
+}
+
+/// Parses the tag at the start of `source`, returning it and its length.
+fn parse_tag(source: &str) -> Option<(Tag<'_>, usize)> {
+    let end = source.find('>')?;
+    let inner = &source[1..end];
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+
+    let name_end = inner.find(|c: char| c.is_whitespace() || c == '/').unwrap_or(inner.len());
+    let name = &inner[..name_end];
+    let valid = name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return None;
+    }
+
+    let tag = if closing {
+        Tag::Close { name }
+    } else {
+        Tag::Open { name, self_closing: inner.ends_with('/') }
+    };
+    Some((tag, end + 1))
+}
+
+fn is_line_break(name: &str) -> bool {
+    name.eq_ignore_ascii_case("br") || name.eq_ignore_ascii_case("hr")
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &mut String) {
+    if !text.is_empty() {
+        nodes.push(Node::Text(decode_entities(text)));
+        text.clear();
+    }
+}
+
+/// Closes every open element from `index` onwards, adding each to its parent.
+fn close(open: &mut Vec<(String, Vec<Node>)>, index: usize) {
+    while open.len() > index {
+        let (tag, children) = open.pop().unwrap();
+        open.last_mut().unwrap().1.push(Node::Element(Element { tag, children }));
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    const ENTITIES: [(&str, &str); 7] = [
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&quot;", "\""),
+        ("&#39;", "'"),
+        ("&apos;", "'"),
+        ("&nbsp;", "\u{a0}"),
+        ("&amp;", "&"),
+    ];
+    if !text.contains('&') {
+        return text.to_owned();
+    }
+    ENTITIES.iter().fold(text.to_owned(), |text, (entity, value)| text.replace(entity, value))
+}
+
+fn plain(nodes: &[Node], output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::LineBreak => output.push('\n'),
+            Node::Element(element) => plain(&element.children, output),
+        }
+    }
+}
+
+fn markdown_marker(tag: &str) -> Option<&'static str> {
+    match tag {
+        "attention" | "passive" | "active" | "unique" | "raritymythic" | "raritylegendary"
+        | "keywordmajor" | "spellname" | "b" | "strong" => Some("**"),
+        "flavortext" | "rules" | "i" | "em" => Some("*"),
+        _ => None,
+    }
+}
+
+fn markdown(nodes: &[Node], emphasis: &mut Vec<&'static str>, output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                for c in text.chars() {
+                    if matches!(c, '\\' | '*' | '_' | '~' | '`' | '|' | '[' | ']' | '<' | '>' | '#')
+                    {
+                        output.push('\\');
+                    }
+                    output.push(c);
+                }
+            }
+            Node::LineBreak => output.push_str("  \n"),
+            Node::Element(element) => {
+                // Emphasis inside the same emphasis would close it instead,
+                // e.g. `****x****` isn't bold, so it's left out.
+                let marker =
+                    markdown_marker(&element.tag).filter(|marker| !emphasis.contains(marker));
+                emphasis.extend(marker);
+                let mut inner = String::new();
+                markdown(&element.children, emphasis, &mut inner);
+                if marker.is_some() {
+                    emphasis.pop();
+                }
+                match marker {
+                    // Emphasis can't start or end with whitespace, so it's
+                    // moved outside of the markers.
+                    Some(marker) if !inner.trim().is_empty() => {
+                        let trimmed = inner.trim_start();
+                        output.push_str(&inner[..inner.len() - trimmed.len()]);
+                        let content = trimmed.trim_end();
+                        output.push_str(marker);
+                        output.push_str(content);
+                        output.push_str(marker);
+                        output.push_str(&trimmed[content.len()..]);
+                    }
+                    _ => output.push_str(&inner),
+                }
+            }
+        }
+    }
+}
+
+fn html(nodes: &[Node], output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                for c in text.chars() {
+                    match c {
+                        '&' => output.push_str("&amp;"),
+                        '<' => output.push_str("&lt;"),
+                        '>' => output.push_str("&gt;"),
+                        '"' => output.push_str("&quot;"),
+                        '\'' => output.push_str("&#39;"),
+                        c => output.push(c),
+                    }
+                }
+            }
+            Node::LineBreak => output.push_str("<br>"),
+            Node::Element(element) => {
+                // Tag names are already limited to characters safe in a class.
+                output.push_str(&format!("<span class=\"ddragon-{}\">", element.tag));
+                html(&element.children, output);
+                output.push_str("</span>");
+            }
+        }
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+fn ansi_style(tag: &str) -> Option<&'static str> {
+    match tag {
+        "attention" | "keywordmajor" | "spellname" | "b" | "strong" => Some("1"),
+        "passive" | "active" | "unique" => Some("1;33"),
+        "physicaldamage" | "scalead" => Some("33"),
+        "magicdamage" | "scaleap" => Some("34"),
+        "truedamage" => Some("1;37"),
+        "healing" | "speed" => Some("32"),
+        "shield" => Some("36"),
+        "status" | "raritymythic" => Some("35"),
+        "raritylegendary" => Some("91"),
+        "flavortext" | "rules" | "i" | "em" => Some("3"),
+        _ => None,
+    }
+}
+
+fn ansi(nodes: &[Node], styles: &mut Vec<&'static str>, output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::LineBreak => output.push('\n'),
+            Node::Element(element) => match ansi_style(&element.tag) {
+                Some(style) => {
+                    styles.push(style);
+                    output.push_str(&format!("\x1b[{style}m"));
+                    ansi(&element.children, styles, output);
+                    styles.pop();
+                    // Terminals can't undo a single style, so reset and then
+                    // apply the styles of every element still open.
+                    output.push_str(ANSI_RESET);
+                    for style in styles.iter() {
+                        output.push_str(&format!("\x1b[{style}m"));
+                    }
+                }
+                None => ansi(&element.children, styles, output),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(tag: &str, children: Vec<Node>) -> Node {
+        Node::Element(Element { tag: tag.to_owned(), children })
+    }
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.to_owned())
+    }
+
+    #[test]
+    fn parses_nested_tags() {
+        let parsed =
+            RichText::parse("<mainText><stats><attention>20</attention> AP</stats><br></mainText>");
+        assert_eq!(
+            parsed.nodes,
+            vec![element(
+                "maintext",
+                vec![
+                    element("stats", vec![element("attention", vec![text("20")]), text(" AP")]),
+                    Node::LineBreak
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn tolerates_unknown_and_malformed_tags() {
+        let parsed =
+            RichText::parse("<newTag key='a'>x</b> < 5 &amp; <font color='#fff'>y<unclosed>z");
+        assert_eq!(
+            parsed.nodes,
+            vec![element(
+                "newtag",
+                vec![
+                    text("x < 5 & "),
+                    element("font", vec![text("y"), element("unclosed", vec![text("z")])])
+                ]
+            )]
+        );
+        assert_eq!(parsed.to_plain_text(), "x < 5 & yz");
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let parsed =
+            RichText::parse("<passive>Spellblade: </passive>Deals *2x*<br><rules>Once</rules>");
+        assert_eq!(parsed.to_markdown(), "**Spellblade:** Deals \\*2x\\*  \n*Once*");
+    }
+
+    #[test]
+    fn renders_nested_emphasis_once() {
+        let parsed = RichText::parse(
+            "<passive><attention>x</attention></passive> <rules>a <i>b</i> <b>c</b></rules>",
+        );
+        assert_eq!(parsed.to_markdown(), "**x** *a b **c***");
+    }
+
+    #[test]
+    fn renders_sanitized_html() {
+        let parsed = RichText::parse("<attention onclick='x'>20</attention> &lt;script&gt;<br/>");
+        assert_eq!(
+            parsed.to_html(),
+            "<span class=\"ddragon-attention\">20</span> &lt;script&gt;<br>"
+        );
+    }
+
+    #[test]
+    fn renders_ansi_with_nested_styles() {
+        let parsed = RichText::parse("<passive>A <magicDamage>B</magicDamage> C</passive>");
+        assert_eq!(parsed.to_ansi(), "\x1b[1;33mA \x1b[34mB\x1b[0m\x1b[1;33m C\x1b[0m");
+    }
+}
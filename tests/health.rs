@@ -1,6 +1,17 @@
 #[cfg(any(feature = "sync", feature = "async"))]
 use std::{env::temp_dir, fs::remove_dir_all, time::Instant};
 
+/// Warns about fields the models don't know about yet, without failing the
+/// check.
+#[cfg(any(feature = "sync", feature = "async"))]
+fn print_drift(drift: ddragon::drift::DriftReport) {
+    use colored::Colorize;
+
+    if !drift.is_empty() {
+        println!("{}\n{drift}", "Fields missing from the models:".yellow());
+    }
+}
+
 #[cfg(feature = "sync")]
 #[test]
 fn health_check() {
@@ -19,10 +30,12 @@ fn health_check() {
                 .middleware(CacheMiddleware::new(cache_dir))
                 .build(),
         ))
+        .report_drift(true)
         .build()
         .unwrap();
 
     let uncached_start = Instant::now();
+    let languages = client.languages().unwrap();
     let challenges = client.challenges().unwrap();
     let champion = client.champion("MonkeyKing").unwrap();
     let champion_image = client.image_of(&champion).unwrap();
@@ -51,6 +64,7 @@ fn health_check() {
     let uncached_duration = uncached_start.elapsed();
 
     let cached_start = Instant::now();
+    let cached_languages = client.languages().unwrap();
     let cached_challenges = client.challenges().unwrap();
     let cached_champion = client.champion("MonkeyKing").unwrap();
     let cached_champion_image = client.image_of(&cached_champion).unwrap();
@@ -81,8 +95,10 @@ fn health_check() {
     println!();
     dbg!(uncached_duration);
     dbg!(cached_duration);
+    print_drift(client.drift_report().unwrap());
 
     assert!(cached_duration < uncached_duration);
+    assert_eq!(languages, cached_languages);
     assert_eq!(challenges, cached_challenges);
     assert_eq!(champion, cached_champion);
     assert_eq!(champion_image, cached_champion_image);
@@ -114,8 +130,7 @@ fn health_check() {
 async fn async_health_check() {
     use std::time::Duration;
 
-    use ddragon::AsyncClientBuilder;
-    use http_cache_reqwest::{CACacheManager, Cache, CacheMode, HttpCache, HttpCacheOptions};
+    use ddragon::{AsyncClientBuilder, async_cache_middleware::AsyncCacheMiddleware};
     use reqwest::Client;
     use reqwest_middleware::ClientBuilder as MiddlewareClientBuilder;
 
@@ -128,18 +143,16 @@ async fn async_health_check() {
             MiddlewareClientBuilder::new(
                 Client::builder().timeout(Duration::from_secs(10)).build().unwrap(),
             )
-            .with(Cache(HttpCache {
-                mode: CacheMode::ForceCache,
-                manager: CACacheManager { path: cache_dir.into() },
-                options: HttpCacheOptions::default(),
-            }))
+            .with(AsyncCacheMiddleware::new(cache_dir))
             .build(),
         )
+        .report_drift(true)
         .build()
         .await
         .unwrap();
 
     let uncached_start = Instant::now();
+    let languages = client.languages().await.unwrap();
     let challenges = client.challenges().await.unwrap();
     let champion = client.champion("MonkeyKing").await.unwrap();
     let champion_image = client.image_of(&champion).await.unwrap();
@@ -168,6 +181,7 @@ async fn async_health_check() {
     let uncached_duration = uncached_start.elapsed();
 
     let cached_start = Instant::now();
+    let cached_languages = client.languages().await.unwrap();
     let cached_challenges = client.challenges().await.unwrap();
     let cached_champion = client.champion("MonkeyKing").await.unwrap();
     let cached_champion_image = client.image_of(&cached_champion).await.unwrap();
@@ -198,8 +212,10 @@ async fn async_health_check() {
     println!();
     dbg!(uncached_duration);
     dbg!(cached_duration);
+    print_drift(client.drift_report().unwrap());
 
     assert!(cached_duration < uncached_duration);
+    assert_eq!(languages, cached_languages);
     assert_eq!(challenges, cached_challenges);
     assert_eq!(champion, cached_champion);
     assert_eq!(champion_image, cached_champion_image);